@@ -0,0 +1,284 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt;
+use std::fs;
+use std::io;
+use std::process;
+
+use crate::{file_path, invalid_arg, write_file_atomic, PartResult};
+
+/// Confirmed answers for one day, stored in `src/answers/<year>/NN.toml`.
+///
+/// The file is a small TOML subset: `part_one` and `part_two` keys holding strings or bare integers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    /// Loads the stored answers for `day`. A missing file means nothing has been recorded yet.
+    pub fn load(year: i16, day: u8) -> Result<Self, String> {
+        let path = file_path("answers", year, day, "toml").map_err(|e| e.to_string())?;
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                Self::parse(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, year: i16, day: u8) -> io::Result<()> {
+        let path = file_path("answers", year, day, "toml")?;
+        write_file_atomic(&path, &self.to_toml())
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+        for (i, line) in contents.split("\n").enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `key = value`", i + 1))?;
+            let value = parse_value(value.trim()).map_err(|e| format!("line {}: {}", i + 1, e))?;
+            match key.trim() {
+                "part_one" => answers.part_one = Some(value),
+                "part_two" => answers.part_two = Some(value),
+                key => return Err(format!("line {}: unknown key \"{}\"", i + 1, key)),
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        if let Some(answer) = &self.part_one {
+            toml.push_str(&format!("part_one = \"{}\"\n", escape(answer)));
+        }
+        if let Some(answer) = &self.part_two {
+            toml.push_str(&format!("part_two = \"{}\"\n", escape(answer)));
+        }
+        toml
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Stores the answer of every solved part, replacing what was there before.
+    pub fn record(&mut self, result: &PartResult) {
+        if let Some(answer) = &result.answer {
+            match result.part {
+                1 => self.part_one = Some(answer.clone()),
+                2 => self.part_two = Some(answer.clone()),
+                _ => {}
+            }
+        }
+    }
+
+    pub fn check(&self, result: &PartResult) -> Verdict {
+        match (self.get(result.part), &result.answer) {
+            (None, _) => Verdict::Unrecorded,
+            (Some(expected), None) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), Some(actual)) => Verdict::Mismatch {
+                expected: expected.to_string(),
+                actual: actual.clone(),
+            },
+        }
+    }
+}
+
+pub(crate) fn parse_value(value: &str) -> Result<String, String> {
+    let Some(quoted) = value.strip_prefix('"') else {
+        if value.is_empty() || value.contains(char::is_whitespace) {
+            return Err(format!("invalid value \"{}\"", value));
+        }
+        return Ok(value.to_string());
+    };
+    let mut parsed = String::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                let rest = chars.as_str().trim();
+                if !rest.is_empty() && !rest.starts_with('#') {
+                    return Err(format!("unexpected \"{}\" after string", rest));
+                }
+                return Ok(parsed);
+            }
+            '\\' => match chars.next() {
+                Some('"') => parsed.push('"'),
+                Some('\\') => parsed.push('\\'),
+                Some('n') => parsed.push('\n'),
+                Some(c) => return Err(format!("unsupported escape \"\\{}\"", c)),
+                None => break,
+            },
+            c => parsed.push(c),
+        }
+    }
+    Err("unterminated string".to_string())
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// The outcome of comparing one part's result with its stored answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The result matches the stored answer.
+    Pass,
+    /// The part returned `None` although an answer is stored.
+    Fail { expected: String },
+    /// The part returned a different answer.
+    Mismatch { expected: String, actual: String },
+    /// No answer has been recorded for this part yet.
+    Unrecorded,
+}
+
+impl Verdict {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Verdict::Pass | Verdict::Unrecorded)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "✅ pass"),
+            Verdict::Fail { expected } => write!(f, "❌ fail (not solved, expected {})", expected),
+            Verdict::Mismatch { expected, actual } => {
+                write!(f, "❌ mismatch (expected {}, got {})", expected, actual)
+            }
+            Verdict::Unrecorded => write!(f, "no answer recorded"),
+        }
+    }
+}
+
+/// What `cargo solve` does with the stored answers after printing the results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnswerMode {
+    #[default]
+    Ignore,
+    /// `--verify`: compare each result with the stored answer.
+    Verify,
+    /// `--record`: store the answer of each solved part.
+    Record,
+}
+
+impl AnswerMode {
+    /// Reads the `--verify` and `--record` flags.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        match (args.contains("--verify"), args.contains("--record")) {
+            (false, false) => Ok(AnswerMode::Ignore),
+            (true, false) => Ok(AnswerMode::Verify),
+            (false, true) => Ok(AnswerMode::Record),
+            (true, true) => Err(invalid_arg("--record", "cannot be used with --verify")),
+        }
+    }
+
+    /// Applies the mode to one day's results. Returns false if any part failed verification.
+    pub fn apply(self, year: i16, day: u8, results: &[PartResult]) -> bool {
+        if self == AnswerMode::Ignore {
+            return true;
+        }
+        let mut answers = match Answers::load(year, day) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("Failed to load answers: {}", e);
+                process::exit(1);
+            }
+        };
+        match self {
+            AnswerMode::Ignore => true,
+            AnswerMode::Verify => results.iter().fold(true, |ok, result| {
+                let verdict = answers.check(result);
+                println!("Part {}: {}", result.part, verdict);
+                ok && verdict.is_ok()
+            }),
+            AnswerMode::Record => {
+                results.iter().for_each(|result| answers.record(result));
+                match answers.save(year, day) {
+                    Ok(_) => println!("Recorded answers for day {}, {}", day, year),
+                    Err(e) => {
+                        eprintln!("Failed to record answers: {}", e);
+                        process::exit(1);
+                    }
+                }
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            year: 2022,
+            day: 5,
+            part,
+            answer: answer.map(String::from),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("# day 5\npart_one = \"CMZ\"\npart_two = 12\n").unwrap();
+        assert_eq!(answers.get(1), Some("CMZ"));
+        assert_eq!(answers.get(2), Some("12"));
+        assert_eq!(Answers::parse("").unwrap(), Answers::default());
+        assert!(Answers::parse("part_three = 1").is_err());
+        assert!(Answers::parse("part_one = \"open").is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let answers = Answers {
+            part_one: Some("a \"quoted\" \\ answer".to_string()),
+            part_two: None,
+        };
+        assert_eq!(Answers::parse(&answers.to_toml()).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.record(&result(1, Some("CMZ")));
+        answers.record(&result(2, None));
+        assert_eq!(answers.check(&result(1, Some("CMZ"))), Verdict::Pass);
+        assert_eq!(
+            answers.check(&result(1, Some("MCD"))),
+            Verdict::Mismatch {
+                expected: "CMZ".to_string(),
+                actual: "MCD".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(&result(1, None)),
+            Verdict::Fail {
+                expected: "CMZ".to_string()
+            }
+        );
+        assert_eq!(answers.check(&result(2, Some("MCD"))), Verdict::Unrecorded);
+    }
+}
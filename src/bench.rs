@@ -0,0 +1,207 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::SolveError;
+use crate::params::Params;
+use crate::{invalid_arg, PartResult, Solution};
+
+pub const DEFAULT_WARMUP: usize = 3;
+
+/// How many times each phase is run when benchmarking.
+/// Warmup runs are executed first and discarded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl BenchConfig {
+    /// Reads `--bench <runs>` and `--warmup <runs>`.
+    /// Returns `None` unless `--bench` was passed.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        let runs = args.opt_value_from_str("--bench")?;
+        let warmup = args.opt_value_from_str("--warmup")?;
+        match runs {
+            None => Ok(None),
+            Some(0) => Err(invalid_arg("--bench 0", "needs at least one run")),
+            Some(runs) => Ok(Some(BenchConfig {
+                warmup: warmup.unwrap_or(DEFAULT_WARMUP),
+                runs,
+            })),
+        }
+    }
+}
+
+/// Summary statistics over the samples of one benchmarked phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "cannot compute stats without samples");
+        samples.sort();
+        let runs = samples.len();
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+        let mean = samples.iter().sum::<Duration>() / runs as u32;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+        Stats {
+            runs,
+            min: samples[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Benchmark of one part. `stats` covers only the solver, parsing is measured separately.
+#[derive(Debug, Clone)]
+pub struct PartBench {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub stats: Stats,
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub year: i16,
+    pub day: u8,
+    pub parse: Stats,
+    pub parts: [PartBench; 2],
+}
+
+impl BenchReport {
+    /// The results of the last run of each part, timed by the median of parsing plus the median
+    /// of the part, so that they cover the same as the time of a single run.
+    pub fn results(&self) -> [PartResult; 2] {
+        self.parts.clone().map(|bench| PartResult {
+            year: self.year,
+            day: self.day,
+            part: bench.part,
+            answer: bench.answer,
+            elapsed: self.parse.median + bench.stats.median,
+        })
+    }
+
+    /// Median time of parsing once and solving every solved part.
+    pub fn total(&self) -> Duration {
+        self.parse.median
+            + self
+                .parts
+                .iter()
+                .filter(|bench| bench.answer.is_some())
+                .map(|bench| bench.stats.median)
+                .sum::<Duration>()
+    }
+}
+
+/// Runs `f` `config.warmup + config.runs` times and returns the stats of the timed runs,
+/// along with the output of the last one.
+fn sample<T>(config: BenchConfig, mut f: impl FnMut() -> T) -> (Stats, T) {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+    let mut samples = Vec::with_capacity(config.runs);
+    let mut output = None;
+    for _ in 0..config.runs {
+        let timer = Instant::now();
+        let result = black_box(f());
+        samples.push(timer.elapsed());
+        output = Some(result);
+    }
+    (Stats::from_samples(&mut samples), output.unwrap())
+}
+
+pub(crate) fn bench_parts<S: Solution>(
+    input: &str,
+    params: &Params,
+    config: BenchConfig,
+) -> Result<BenchReport, SolveError> {
+    let (parse, input) = sample(config, || S::parse_with(black_box(input), params));
+    let input = input.map_err(|e| e.in_day(S::YEAR, S::DAY, None))?;
+    let (stats_one, answer_one) = sample(config, || S::try_part_one(black_box(&input)));
+    let answer_one = answer_one.map_err(|e| e.in_day(S::YEAR, S::DAY, Some(1)))?;
+    let (stats_two, answer_two) = sample(config, || S::try_part_two(black_box(&input)));
+    let answer_two = answer_two.map_err(|e| e.in_day(S::YEAR, S::DAY, Some(2)))?;
+    Ok(BenchReport {
+        year: S::YEAR,
+        day: S::DAY,
+        parse,
+        parts: [
+            PartBench {
+                part: 1,
+                answer: answer_one.map(|a| a.to_string()),
+                stats: stats_one,
+            },
+            PartBench {
+                part: 2,
+                answer: answer_two.map(|a| a.to_string()),
+                stats: stats_two,
+            },
+        ],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_odd() {
+        let mut samples = [5, 1, 3].map(Duration::from_millis);
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(stats.runs, 3);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        // population stddev of [1, 3, 5] is sqrt(8/3)
+        assert_eq!(stats.stddev.as_micros(), 1632);
+    }
+
+    #[test]
+    fn test_stats_even() {
+        let mut samples = [4, 2, 2, 8].map(Duration::from_millis);
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(4));
+        assert_eq!(stats.stddev.as_micros(), 2449);
+    }
+
+    #[test]
+    fn test_results_include_parsing() {
+        let stats = |ms| Stats::from_samples(&mut [Duration::from_millis(ms)]);
+        let part = |part, ms| PartBench {
+            part,
+            answer: Some(part.to_string()),
+            stats: stats(ms),
+        };
+        let report = BenchReport {
+            year: 2022,
+            day: 1,
+            parse: stats(5),
+            parts: [part(1, 2), part(2, 3)],
+        };
+        let elapsed = report.results().map(|result| result.elapsed.as_millis());
+        assert_eq!(elapsed, [7, 8]);
+        assert_eq!(report.total(), Duration::from_millis(10));
+    }
+}
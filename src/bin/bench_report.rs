@@ -0,0 +1,94 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use aoc::history::{self, Trend};
+use aoc::{ANSI_BOLD, ANSI_RESET};
+use std::process;
+
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+struct Args {
+    baseline: Option<String>,
+    threshold: f64,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        baseline: args.opt_value_from_str("--baseline")?,
+        threshold: args
+            .opt_value_from_str("--threshold")?
+            .unwrap_or(DEFAULT_THRESHOLD),
+    };
+    aoc::finish_args(args)?;
+    Ok(parsed)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let runs = match history::load() {
+        Ok(runs) => runs,
+        Err(e) => {
+            eprintln!("Failed to load benchmark history: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let Some((current, previous)) = runs.split_last() else {
+        eprintln!(
+            "No runs recorded in {} yet. Run `cargo all --release` first.",
+            history::HISTORY_PATH
+        );
+        process::exit(1);
+    };
+
+    // only compare runs built the same way, debug timings say nothing about release builds.
+    let mut candidates = previous
+        .iter()
+        .rev()
+        .filter(|run| run.profile == current.profile);
+    let baseline = match &args.baseline {
+        Some(commit) => candidates.find(|run| run.commit.starts_with(commit.as_str())),
+        None => candidates.next(),
+    };
+    let Some(baseline) = baseline else {
+        match &args.baseline {
+            Some(commit) => eprintln!("No {} run recorded for commit {}.", current.profile, commit),
+            None => eprintln!("No earlier {} run to compare with.", current.profile),
+        }
+        process::exit(1);
+    };
+
+    println!(
+        "{}Comparing {} ({}) with baseline {}{}",
+        ANSI_BOLD, current.commit, current.profile, baseline.commit, ANSI_RESET
+    );
+
+    let changes = history::compare(baseline, current, args.threshold);
+    for change in &changes {
+        println!("{}", change);
+    }
+
+    let slower = changes.iter().filter(|c| c.trend == Trend::Slower).count();
+    let faster = changes.iter().filter(|c| c.trend == Trend::Faster).count();
+    println!(
+        "{}Summary:{} {} slower, {} faster, {} unchanged (threshold: {}%)",
+        ANSI_BOLD,
+        ANSI_RESET,
+        slower,
+        faster,
+        changes.len() - slower - faster,
+        args.threshold
+    );
+    if slower > 0 {
+        process::exit(1);
+    }
+}
@@ -2,30 +2,39 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use aoc::client::{self, Client, Target};
+use aoc::puzzle;
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"fn parse(input: &str) -> Vec<u32> {
-    input.trim().split("\n").map(|s| s.trim().parse().unwrap()).collect()
-}
+const MODULE_TEMPLATE: &str = r###"use crate::error::{parse_lines, SolveError};
+use crate::Solution;
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let readings = parse(input);
-    None
-}
+pub struct Day{DAY_PADDED};
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let readings = parse(input);
-    None
-}
+impl Solution for Day{DAY_PADDED} {
+    const YEAR: i16 = {YEAR};
+    const DAY: u8 = {DAY};
 
-fn main() {
-    let input = &aoc::read_file("inputs", DAY);
-    aoc::solve!(1, part_one, input);
-    aoc::solve!(2, part_two, input);
+    type Input = Vec<u32>;
+    type PartOne = {TYPE_ONE};
+    type PartTwo = {TYPE_TWO};
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input, |s| Ok(s.trim().parse()?))
+    }
+
+    fn part_one(readings: &Self::Input) -> Option<{TYPE_ONE}> {
+        None
+    }
+
+    fn part_two(readings: &Self::Input) -> Option<{TYPE_TWO}> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -34,21 +43,86 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = aoc::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+        let input = crate::parse_file::<Day{DAY_PADDED}>("examples").unwrap();
+        assert_eq!(Day{DAY_PADDED}::part_one(&input), {EXPECTED_ONE});
     }
 
     #[test]
     fn test_part_two() {
-        let input = aoc::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+        let input = crate::parse_file::<Day{DAY_PADDED}>("examples").unwrap();
+        assert_eq!(Day{DAY_PADDED}::part_two(&input), {EXPECTED_TWO});
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+const YEAR_TEMPLATE: &str = r###"/*
+ * Every solved day of {YEAR} is registered here so it can be run in-process by the runner.
+ * `cargo scaffold` appends new days to the list below.
+ */
+register! {
+}
+"###;
+
+const YEARS_PATH: &str = "src/years/mod.rs";
+
+const PUZZLES_DIR: &str = "puzzles";
+
+struct Args {
+    target: Target,
+    fetch: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    let fetch = args.contains("--fetch");
+    let target = Target::from_args(&mut args)?;
+    aoc::finish_args(args)?;
+    Ok(Args { target, fetch })
+}
+
+/// What `--fetch` found on the puzzle page.
+#[derive(Default)]
+struct Puzzle {
+    markdown: Option<String>,
+    example: Option<String>,
+    expected: [Option<String>; 2],
+}
+
+fn fetch_puzzle(target: &Target) -> Result<Puzzle, String> {
+    // the puzzle page is public, the session cookie only unlocks part two.
+    let session = client::read_session().unwrap_or_else(|_| {
+        println!("No session cookie found, fetching the public puzzle page.");
+        String::new()
+    });
+    let client = Client::new(target.base_url.clone(), session);
+    let html = client.puzzle(target.year, target.day)?;
+    Ok(Puzzle {
+        markdown: Some(puzzle::to_markdown(
+            &html,
+            &client.puzzle_url(target.year, target.day),
+        )),
+        example: puzzle::example(&html),
+        expected: puzzle::expected_answers(&html),
+    })
+}
+
+/// The type a part returns, chosen so the literal from `expected_literal` compares against it.
+fn answer_type(answer: &Option<String>) -> &'static str {
+    match answer {
+        Some(answer) if answer.parse::<u32>().is_ok() => "u32",
+        Some(answer) if answer.parse::<i64>().is_ok() => "i64",
+        Some(_) => "String",
+        None => "u32",
+    }
+}
+
+/// Renders an expected answer as the `Option` the generated test compares against.
+fn expected_literal(answer: &Option<String>) -> String {
+    match answer {
+        Some(answer) if answer.parse::<i64>().is_ok() => format!("Some({})", answer),
+        Some(answer) => format!("Some({:?}.to_string())", answer),
+        None => "None".to_string(),
+    }
 }
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
@@ -56,65 +130,204 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+fn fill_template(template: &str, year: i16, day: u8, expected: &[Option<String>; 2]) -> String {
+    template
+        .replace("{YEAR}", &year.to_string())
+        .replace("{DAY_PADDED}", &format!("{:02}", day))
+        .replace("{DAY}", &day.to_string())
+        .replace("{TYPE_ONE}", answer_type(&expected[0]))
+        .replace("{TYPE_TWO}", answer_type(&expected[1]))
+        .replace("{EXPECTED_ONE}", &expected_literal(&expected[0]))
+        .replace("{EXPECTED_TWO}", &expected_literal(&expected[1]))
+}
+
+fn write_new_file(path: &str, contents: &str) -> Result<(), std::io::Error> {
+    safe_create_file(path)?.write_all(contents.as_bytes())
+}
+
+/// Inserts `entry` as the last line of the `<block> {` macro invocation in the file at `path`.
+fn insert_into_block(path: &str, block: &str, entry: &str) -> Result<(), std::io::Error> {
+    let contents = fs::read_to_string(path)?;
+    if contents.lines().any(|line| line == entry) {
+        return Ok(());
+    }
+
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let header = format!("{} {{", block);
+    let block_end = lines
+        .iter()
+        .position(|line| *line == header)
+        .and_then(|start| {
+            let offset = lines[start..].iter().position(|line| *line == "}")?;
+            Some(start + offset)
+        });
+
+    match block_end {
+        Some(index) => {
+            lines.insert(index, entry);
+            fs::write(path, lines.join("\n") + "\n")
+        }
+        None => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("could not find the `{}` block in \"{}\"", header, path),
+        )),
+    }
+}
+
+/// Creates the year's module on first use and adds it to the `years!` block in `src/years/mod.rs`.
+fn register_year(year: i16, registry_path: &str) -> Result<bool, std::io::Error> {
+    if Path::new(registry_path).exists() {
+        return Ok(false);
+    }
+    fs::create_dir_all(Path::new(registry_path).parent().unwrap())?;
+    write_new_file(
+        registry_path,
+        &fill_template(YEAR_TEMPLATE, year, 0, &[None, None]),
+    )?;
+    insert_into_block(YEARS_PATH, "years!", &format!("    y{},", year))?;
+    Ok(true)
+}
+
+/// Adds the day to the `register!` block of its year, so that `cargo all` picks it up.
+fn register_day(day: u8, registry_path: &str) -> Result<(), std::io::Error> {
+    insert_into_block(
+        registry_path,
+        "register!",
+        &format!("    day{:02} => Day{:02},", day, day),
+    )
+}
+
+/// Creates an empty file, and the folder it lives in if needed.
+fn create_data_file(path: &str) -> Result<File, std::io::Error> {
+    fs::create_dir_all(Path::new(path).parent().unwrap())?;
+    create_file(path)
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
     };
+    let (year, day) = (args.target.year, args.target.day);
+
+    let puzzle = if args.fetch {
+        match fetch_puzzle(&args.target) {
+            Ok(puzzle) => puzzle,
+            Err(e) => {
+                eprintln!("Failed to fetch puzzle: {}", e);
+                process::exit(1);
+            }
+        }
+    } else {
+        Puzzle::default()
+    };
 
     let day_padded = format!("{:02}", day);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let input_path = format!("src/inputs/{}/{}.txt", year, day_padded);
+    let example_path = format!("src/examples/{}/{}.txt", year, day_padded);
+    let registry_path = format!("src/years/y{}/mod.rs", year);
+    let module_path = format!("src/years/y{}/day{}.rs", year, day_padded);
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    match register_year(year, &registry_path) {
+        Ok(true) => {
+            println!("Registered year {} in \"{}\"", year, YEARS_PATH);
+        }
+        Ok(false) => {}
         Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+            eprintln!("Failed to register year: {}", e);
             process::exit(1);
         }
-    };
+    }
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match write_new_file(
+        &module_path,
+        &fill_template(MODULE_TEMPLATE, year, day, &puzzle.expected),
+    ) {
         Ok(_) => {
             println!("Created module file \"{}\"", &module_path);
         }
         Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+            eprintln!("Failed to create module file: {}", e);
             process::exit(1);
         }
     }
 
-    match create_file(&input_path) {
+    match register_day(day, &registry_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Registered day {} in \"{}\"", day, registry_path);
         }
         Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+            eprintln!("Failed to register day: {}", e);
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
+    match create_data_file(&input_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty input file \"{}\"", &input_path);
         }
         Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
+            eprintln!("Failed to create input file: {}", e);
             process::exit(1);
         }
     }
 
+    let example_is_empty = fs::metadata(&example_path).map_or(true, |meta| meta.len() == 0);
+    match &puzzle.example {
+        Some(example) if example_is_empty => {
+            match aoc::write_file_atomic(Path::new(&example_path), example) {
+                Ok(_) => {
+                    println!("Wrote example from puzzle to \"{}\"", &example_path);
+                }
+                Err(e) => {
+                    eprintln!("Failed to write example file: {}", e);
+                    process::exit(1);
+                }
+            }
+        }
+        _ => match create_data_file(&example_path) {
+            Ok(_) => {
+                println!("Created empty example file \"{}\"", &example_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {}", e);
+                process::exit(1);
+            }
+        },
+    }
+
+    if let Some(markdown) = &puzzle.markdown {
+        let puzzle_path = format!("{}/{}/{}.md", PUZZLES_DIR, year, day_padded);
+        match aoc::write_file_atomic(Path::new(&puzzle_path), markdown) {
+            Ok(_) => {
+                println!("Wrote puzzle description to \"{}\"", &puzzle_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to write puzzle description: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
     println!("---");
+    let year_flag = if year == aoc::DEFAULT_YEAR {
+        String::new()
+    } else {
+        format!(" --year {}", year)
+    };
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type `cargo solve {}{}` to run your solution.",
+        &day_padded, year_flag
     );
 }
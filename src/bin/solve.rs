@@ -0,0 +1,51 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use aoc::RunOptions;
+use std::process;
+
+struct Args {
+    day: u8,
+    year: i16,
+    options: RunOptions,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let options = RunOptions::from_args(&mut args)?;
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or(aoc::DEFAULT_YEAR);
+    // Free arguments are read last, so that flags are not mistaken for the day
+    let day = args.free_from_str()?;
+    aoc::finish_args(args)?;
+    Ok(Args { day, year, options })
+}
+
+fn main() {
+    // cargo forwards everything after the day to us, so a late `--release` would be silently ignored.
+    if std::env::args().any(|arg| arg == "--release") {
+        eprintln!("`--release` must come before the day. example: `cargo solve --release 01`");
+        process::exit(1);
+    }
+
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    match aoc::years::find(args.year, args.day) {
+        Some(solver) => aoc::run(solver, args.options),
+        None => {
+            eprintln!(
+                "Day {}, {} is not registered. Type `cargo scaffold {} --year {}` to create it.",
+                args.day, args.year, args.day, args.year
+            );
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use aoc::answers::Answers;
+use aoc::client::{self, Client, Target};
+use aoc::params::Params;
+use aoc::submit::{self, Outcome, Submission};
+use std::process;
+
+struct Args {
+    target: Target,
+    part: u8,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let target = Target::from_args(&mut args)?;
+    let part = args.free_from_str()?;
+    aoc::finish_args(args)?;
+    Ok(Args { target, part })
+}
+
+fn exit_with_error(context: &str, e: impl std::fmt::Display) -> ! {
+    eprintln!("{}: {}", context, e);
+    process::exit(1);
+}
+
+fn main() {
+    let Args { target, part } =
+        parse_args().unwrap_or_else(|e| exit_with_error("Failed to process arguments", e));
+    if part != 1 && part != 2 {
+        exit_with_error("Failed to process arguments", "part must be 1 or 2");
+    }
+
+    let Some(solver) = aoc::years::find(target.year, target.day) else {
+        exit_with_error(
+            "Nothing to submit",
+            format!("day {}, {} is not registered", target.day, target.year),
+        );
+    };
+    let input = aoc::try_read_file("inputs", target.year, target.day)
+        .unwrap_or_else(|e| exit_with_error("Failed to read input", e));
+    let params = Params::load("inputs", target.year, target.day)
+        .unwrap_or_else(|e| exit_with_error("Failed to load parameters", e));
+    let results = solver
+        .solve(&input, &params)
+        .unwrap_or_else(|e| exit_with_error("Failed to solve", e));
+    let result = &results[part as usize - 1];
+    let Some(answer) = &result.answer else {
+        exit_with_error("Nothing to submit", format!("part {} is not solved", part));
+    };
+
+    let log = submit::load_log().unwrap_or_else(|e| exit_with_error("Failed to read log", e));
+    if let Err(e) = submit::check_guess(&log, target.year, target.day, part, answer) {
+        exit_with_error("Refusing to submit", e);
+    }
+
+    let session = client::read_session()
+        .unwrap_or_else(|e| exit_with_error("Failed to load session cookie", e));
+    let client = Client::new(target.base_url, session);
+
+    println!(
+        "Submitting {} for day {} part {}, {}...",
+        answer, target.day, part, target.year
+    );
+    let level = part.to_string();
+    let response = client
+        .post_form(
+            &format!("/{}/day/{}/answer", target.year, target.day),
+            &[("level", &level), ("answer", answer)],
+        )
+        .unwrap_or_else(|e| exit_with_error("Failed to submit answer", e));
+
+    let outcome = Outcome::parse(&response);
+    println!("{}", outcome);
+
+    let submission = Submission::new(target.year, target.day, part, answer, outcome);
+    if let Err(e) = submit::append_log(&submission) {
+        eprintln!("Failed to record submission in {}: {}", submit::LOG_PATH, e);
+    }
+
+    match outcome {
+        Outcome::Correct => {
+            let mut answers = Answers::load(target.year, target.day)
+                .unwrap_or_else(|e| exit_with_error("Failed to load answers", e));
+            answers.record(result);
+            match answers.save(target.year, target.day) {
+                Ok(_) => println!("Recorded answer for day {} part {}", target.day, part),
+                Err(e) => exit_with_error("Failed to record answer", e),
+            }
+        }
+        Outcome::AlreadySolved => {}
+        _ => process::exit(1),
+    }
+}
@@ -0,0 +1,90 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use aoc::answers::{Answers, Verdict};
+use aoc::params::Params;
+use aoc::{ANSI_BOLD, ANSI_RESET};
+use std::process;
+
+struct Args {
+    day: Option<u8>,
+    year: Option<i16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+    let day = args.opt_free_from_str()?;
+    aoc::finish_args(args)?;
+    Ok(Args { day, year })
+}
+
+fn main() {
+    let Args { day, year } = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let (mut passed, mut failed, mut unrecorded) = (0, 0, 0);
+
+    for solver in aoc::years::all().iter().filter(|solver| {
+        year.is_none_or(|year| solver.year == year) && day.is_none_or(|day| solver.day == day)
+    }) {
+        let answers = match Answers::load(solver.year, solver.day) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("Failed to load answers: {}", e);
+                process::exit(1);
+            }
+        };
+        if answers == Answers::default() {
+            unrecorded += 2;
+            continue;
+        }
+
+        println!(
+            "{}{} Day {:02}{}",
+            ANSI_BOLD, solver.year, solver.day, ANSI_RESET
+        );
+        let input = match aoc::try_read_file("inputs", solver.year, solver.day) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("❌ fail (could not read input: {})", e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let results = match Params::load("inputs", solver.year, solver.day)
+            .and_then(|params| solver.solve(&input, &params))
+        {
+            Ok(results) => results,
+            Err(e) => {
+                println!("❌ fail ({})", e);
+                failed += 1;
+                continue;
+            }
+        };
+        for result in results {
+            let verdict = answers.check(&result);
+            println!("Part {}: {}", result.part, verdict);
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Unrecorded => unrecorded += 1,
+                Verdict::Fail { .. } | Verdict::Mismatch { .. } => failed += 1,
+            }
+        }
+    }
+
+    println!(
+        "{}Verified:{} {} passed, {} failed, {} without a recorded answer",
+        ANSI_BOLD, ANSI_RESET, passed, failed, unrecorded
+    );
+    if failed > 0 {
+        process::exit(1);
+    }
+}
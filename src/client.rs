@@ -0,0 +1,227 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::DEFAULT_YEAR;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const SESSION_FILE: &str = ".adventofcode.session";
+/// Advent of Code asks automated tools to identify themselves.
+pub const USER_AGENT: &str = "github.com/mrob95/aoc2022 template (ureq)";
+
+/// Reads the session cookie from `AOC_SESSION`, falling back to `~/.adventofcode.session`.
+pub fn read_session() -> Result<String, String> {
+    if let Ok(session) = env::var(SESSION_VAR) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+        .ok_or(format!(
+            "{} is not set and no home directory was found",
+            SESSION_VAR
+        ))?;
+    let path = home.join(SESSION_FILE);
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        Ok(_) => Err(format!("session file \"{}\" is empty", path.display())),
+        Err(e) => Err(format!(
+            "could not read session cookie from {} or \"{}\": {}",
+            SESSION_VAR,
+            path.display(),
+            e
+        )),
+    }
+}
+
+/// The base URL from `--base-url`, `AOC_BASE_URL` or the real site, in that order.
+pub fn base_url(flag: Option<String>) -> String {
+    flag.or_else(|| env::var(BASE_URL_VAR).ok())
+        .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// The puzzle a command operates on, parsed the same way by `cargo download` and `cargo submit`:
+/// the day as the first free argument, followed by optional `--year` and `--base-url` flags.
+pub struct Target {
+    pub day: u8,
+    pub year: i16,
+    pub base_url: String,
+}
+
+impl Target {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Target {
+            day: args.free_from_str()?,
+            year: args
+                .opt_value_from_str(["-y", "--year"])?
+                .unwrap_or(DEFAULT_YEAR),
+            base_url: base_url(args.opt_value_from_str("--base-url")?),
+        })
+    }
+}
+
+/// A minimal Advent of Code client that authenticates with the session cookie.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: String, session: String) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Client {
+            base_url,
+            session,
+            agent,
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Fetches `path` (e.g. `/2022/day/1/input`) relative to the base URL.
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        self.send(self.agent.get(&format!("{}{}", self.base_url, path)), None)
+    }
+
+    /// Posts an url-encoded form to `path` relative to the base URL.
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        self.send(
+            self.agent.post(&format!("{}{}", self.base_url, path)),
+            Some(form),
+        )
+    }
+
+    fn send(
+        &self,
+        request: ureq::Request,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, String> {
+        let url = request.url().to_string();
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| format!("could not read response from {}: {}", url, e)),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                let hint = match code {
+                    400 | 500 => " (is the session cookie still valid?)",
+                    404 => " (is the puzzle unlocked yet?)",
+                    _ => "",
+                };
+                Err(format!(
+                    "{} returned status {}{}: {}",
+                    url,
+                    code,
+                    hint,
+                    body.trim()
+                ))
+            }
+            Err(e) => Err(format!("request to {} failed: {}", url, e)),
+        }
+    }
+
+    pub fn input(&self, year: i16, day: u8) -> Result<String, String> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    pub fn puzzle_url(&self, year: i16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    /// The HTML of the puzzle page. Part two is only included for a logged in session
+    /// that has solved part one.
+    pub fn puzzle(&self, year: i16, day: u8) -> Result<String, String> {
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves a single canned response and returns the request head it received.
+    fn serve_once(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut head = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            head
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_input() {
+        let (base_url, server) = serve_once("200 OK", "1000\n2000\n");
+        let client = Client::new(base_url, "abc123".to_string());
+        assert_eq!(client.input(2022, 1), Ok("1000\n2000\n".to_string()));
+
+        let head = server.join().unwrap().to_lowercase();
+        assert!(head.starts_with("get /2022/day/1/input http/1.1"));
+        assert!(head.contains("cookie: session=abc123"));
+        assert!(head.contains(&format!("user-agent: {}", USER_AGENT.to_lowercase())));
+    }
+
+    #[test]
+    fn test_error_status() {
+        let (base_url, server) = serve_once("404 Not Found", "Not found");
+        let client = Client::new(base_url, "abc123".to_string());
+        let error = client.input(2022, 25).unwrap_err();
+        assert!(error.contains("status 404"), "{}", error);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_base_url() {
+        assert_eq!(
+            base_url(Some("http://localhost:8080/".to_string())),
+            "http://localhost:8080"
+        );
+    }
+}
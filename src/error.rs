@@ -0,0 +1,162 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt;
+use std::num::ParseIntError;
+
+/// Why a solution could not use its input.
+/// Solutions only provide the message, and the line where they know it. The solve pipeline
+/// fills in the year, day and part before the error is shown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub year: Option<i16>,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    /// 1-based line number in the input file.
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        SolveError {
+            year: None,
+            day: None,
+            part: None,
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Makes the line number relative to `start`, the line a sub-section of the input begins on.
+    /// An error without a line is placed on `start` itself.
+    pub fn offset(mut self, start: usize) -> Self {
+        self.line = Some(start + self.line.map_or(0, |line| line - 1));
+        self
+    }
+
+    pub(crate) fn in_day(mut self, year: i16, day: u8, part: Option<u8>) -> Self {
+        self.year = Some(year);
+        self.day = Some(day);
+        self.part = part;
+        self
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut location = vec![];
+        if let Some(day) = self.day {
+            location.push(format!("day {}", day));
+        }
+        if let Some(year) = self.year {
+            location.push(year.to_string());
+        }
+        if let Some(part) = self.part {
+            location.push(format!("part {}", part));
+        }
+        if let Some(line) = self.line {
+            location.push(format!("line {}", line));
+        }
+        if location.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", location.join(", "), self.message)
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<String> for SolveError {
+    fn from(message: String) -> Self {
+        SolveError::new(message)
+    }
+}
+
+impl From<&str> for SolveError {
+    fn from(message: &str) -> Self {
+        SolveError::new(message)
+    }
+}
+
+impl From<ParseIntError> for SolveError {
+    fn from(e: ParseIntError) -> Self {
+        SolveError::new(format!("invalid number: {}", e))
+    }
+}
+
+/// Parses each line of `input` with `f`. Errors are tagged with the line they occurred on.
+pub fn parse_lines<T>(
+    input: &str,
+    mut f: impl FnMut(&str) -> Result<T, SolveError>,
+) -> Result<Vec<T>, SolveError> {
+    input
+        .trim_end()
+        .split("\n")
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.offset(i + 1)))
+        .collect()
+}
+
+/// Parses each block of `input` separated by an empty line with `f`.
+/// Line numbers of errors are taken to be relative to the start of their block.
+pub fn parse_blocks<T>(
+    input: &str,
+    mut f: impl FnMut(&str) -> Result<T, SolveError>,
+) -> Result<Vec<T>, SolveError> {
+    let mut start = 1;
+    input
+        .trim_end()
+        .split("\n\n")
+        .map(|block| {
+            let result = f(block).map_err(|e| e.offset(start));
+            start += block.split("\n").count() + 1;
+            result
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(line: &str) -> Result<u32, SolveError> {
+        Ok(line.parse()?)
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines("1\n2\n3\n", number), Ok(vec![1, 2, 3]));
+        let e = parse_lines("1\n2\nx\n", number).unwrap_err();
+        assert_eq!(e.line, Some(3));
+    }
+
+    #[test]
+    fn test_parse_blocks() {
+        let sum = |block: &str| Ok(parse_lines(block, number)?.iter().sum::<u32>());
+        assert_eq!(parse_blocks("1\n2\n\n3\n", sum), Ok(vec![3, 3]));
+        let e = parse_blocks("1\n2\n\n3\n4x\n", sum).unwrap_err();
+        assert_eq!(e.line, Some(5));
+        let e = parse_blocks("1\n\n2\n\n", |_| Err::<u32, _>("empty".into())).unwrap_err();
+        assert_eq!(e.line, Some(1));
+    }
+
+    #[test]
+    fn test_display() {
+        let e = SolveError::new("unknown op \"mulx\"")
+            .at_line(3)
+            .in_day(2022, 10, Some(1));
+        assert_eq!(
+            e.to_string(),
+            "day 10, 2022, part 1, line 3: unknown op \"mulx\""
+        );
+        assert_eq!(SolveError::new("empty input").to_string(), "empty input");
+    }
+}
@@ -2,3 +2,1022 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use aoc::helpers::example_fn;`.
  */
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::error::{parse_lines, SolveError};
+
+pub mod branch_bound;
+pub mod cycle;
+pub mod graph;
+pub mod interval;
+pub mod memo;
+pub mod ocr;
+pub mod parse;
+pub mod rational;
+pub mod search;
+pub mod vm;
+
+/// Offsets to the four orthogonal neighbours: up, right, down, left.
+pub const NEIGHBOURS4: [[isize; 2]; 4] = [[0, -1], [1, 0], [0, 1], [-1, 0]];
+
+/// Offsets to all eight surrounding cells, row by row from the top left.
+pub const NEIGHBOURS8: [[isize; 2]; 8] = [
+    [-1, -1],
+    [0, -1],
+    [1, -1],
+    [-1, 0],
+    [1, 0],
+    [-1, 1],
+    [0, 1],
+    [1, 1],
+];
+
+/// A rectangular grid stored row by row. Positions are `[x, y]`, with `y` growing downwards.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from equally long rows.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, SolveError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                let message = format!("expected a row of {} cells, got {}", width, row.len());
+                return Err(SolveError::new(message).at_line(y + 1));
+            }
+            cells.extend(row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses one row per line, converting every character with `f`.
+    /// Errors, including rows of different lengths, are tagged with their line.
+    pub fn parse(
+        input: &str,
+        mut f: impl FnMut(char) -> Result<T, SolveError>,
+    ) -> Result<Self, SolveError> {
+        let rows = parse_lines(input, |line| line.chars().map(&mut f).collect())?;
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, pos: [usize; 2]) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.index(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: [usize; 2]) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        let index = self.index(pos);
+        Some(&mut self.cells[index])
+    }
+
+    /// The cell at `pos`, with coordinates wrapping around the edges.
+    pub fn get_wrapping(&self, pos: [isize; 2]) -> &T {
+        &self[self.wrap(pos)]
+    }
+
+    pub fn contains(&self, pos: [usize; 2]) -> bool {
+        pos[0] < self.width && pos[1] < self.height
+    }
+
+    /// Converts a signed position to an unsigned one, if it lies within the grid.
+    pub fn checked(&self, pos: [isize; 2]) -> Option<[usize; 2]> {
+        let pos = [usize::try_from(pos[0]).ok()?, usize::try_from(pos[1]).ok()?];
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Wraps a signed position around the edges of the grid. Panics if the grid is empty.
+    pub fn wrap(&self, pos: [isize; 2]) -> [usize; 2] {
+        assert!(
+            self.width > 0 && self.height > 0,
+            "cannot wrap {:?} around an empty grid",
+            pos
+        );
+        [
+            pos[0].rem_euclid(self.width as isize) as usize,
+            pos[1].rem_euclid(self.height as isize) as usize,
+        ]
+    }
+
+    /// The position `delta` away from `pos`, if it lies within the grid.
+    pub fn step(&self, pos: [usize; 2], delta: [isize; 2]) -> Option<[usize; 2]> {
+        self.checked([pos[0] as isize + delta[0], pos[1] as isize + delta[1]])
+    }
+
+    /// The position `delta` away from `pos`, wrapping around the edges.
+    pub fn step_wrapping(&self, pos: [usize; 2], delta: [isize; 2]) -> [usize; 2] {
+        self.wrap([pos[0] as isize + delta[0], pos[1] as isize + delta[1]])
+    }
+
+    /// The orthogonal neighbours of `pos` that lie within the grid.
+    pub fn neighbours4(&self, pos: [usize; 2]) -> impl Iterator<Item = [usize; 2]> + '_ {
+        NEIGHBOURS4
+            .iter()
+            .filter_map(move |delta| self.step(pos, *delta))
+    }
+
+    /// All surrounding positions of `pos` that lie within the grid, diagonals included.
+    pub fn neighbours8(&self, pos: [usize; 2]) -> impl Iterator<Item = [usize; 2]> + '_ {
+        NEIGHBOURS8
+            .iter()
+            .filter_map(move |delta| self.step(pos, *delta))
+    }
+
+    /// The positions reached by repeatedly stepping `delta` from `pos` until leaving the grid.
+    /// `pos` itself is not included.
+    pub fn ray(&self, pos: [usize; 2], delta: [isize; 2]) -> impl Iterator<Item = [usize; 2]> + '_ {
+        std::iter::successors(self.step(pos, delta), move |pos| self.step(*pos, delta))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).map(move |y| &self[[x, y]])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = [usize; 2]> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| [x, y]))
+    }
+
+    /// Every position along with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ([usize; 2], &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position, row by row, whose cell matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<[usize; 2]> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |[x, y]| [y, x])
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rearranged(height, self.width, |[x, y]| [y, height - 1 - x])
+    }
+
+    /// Rotates a quarter turn counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rearranged(self.height, width, |[x, y]| [width - 1 - y, x])
+    }
+
+    /// Renders the grid with one character per cell.
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&f).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn index(&self, pos: [usize; 2]) -> usize {
+        pos[1] * self.width + pos[0]
+    }
+
+    /// Builds a `width` by `height` grid whose cell at `pos` is taken from `source(pos)` here.
+    fn rearranged(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn([usize; 2]) -> [usize; 2],
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| [x, y]))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<[usize; 2]> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: [usize; 2]) -> &T {
+        assert!(self.contains(pos), "{:?} is outside the grid", pos);
+        &self.cells[self.index(pos)]
+    }
+}
+
+impl<T> IndexMut<[usize; 2]> for Grid<T> {
+    fn index_mut(&mut self, pos: [usize; 2]) -> &mut T {
+        assert!(self.contains(pos), "{:?} is outside the grid", pos);
+        let index = self.index(pos);
+        &mut self.cells[index]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The smallest rectangle containing a set of positions, with inclusive corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: [isize; 2],
+    pub max: [isize; 2],
+}
+
+impl Bounds {
+    pub fn new(pos: [isize; 2]) -> Self {
+        Bounds { min: pos, max: pos }
+    }
+
+    /// The bounds of `positions`, or `None` if there are none.
+    pub fn of(positions: impl IntoIterator<Item = [isize; 2]>) -> Option<Self> {
+        let mut positions = positions.into_iter();
+        let mut bounds = Bounds::new(positions.next()?);
+        positions.for_each(|pos| bounds.extend(pos));
+        Some(bounds)
+    }
+
+    pub fn extend(&mut self, pos: [isize; 2]) {
+        self.min = [self.min[0].min(pos[0]), self.min[1].min(pos[1])];
+        self.max = [self.max[0].max(pos[0]), self.max[1].max(pos[1])];
+    }
+
+    pub fn contains(&self, pos: [isize; 2]) -> bool {
+        (0..2).all(|i| self.min[i] <= pos[i] && pos[i] <= self.max[i])
+    }
+
+    /// Whether `pos` lies on the edge, so that removing it could shrink the bounds.
+    fn on_edge(&self, pos: [isize; 2]) -> bool {
+        (0..2).any(|i| pos[i] == self.min[i] || pos[i] == self.max[i])
+    }
+
+    pub fn intersect(&self, other: &Bounds) -> Option<Self> {
+        let min = [self.min[0].max(other.min[0]), self.min[1].max(other.min[1])];
+        let max = [self.max[0].min(other.max[0]), self.max[1].min(other.max[1])];
+        (min[0] <= max[0] && min[1] <= max[1]).then_some(Bounds { min, max })
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max[0] - self.min[0] + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max[1] - self.min[1] + 1) as usize
+    }
+
+    pub fn area(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    /// Every position within the bounds, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = [isize; 2]> {
+        let (min, max) = (self.min, self.max);
+        (min[1]..=max[1]).flat_map(move |y| (min[0]..=max[0]).map(move |x| [x, y]))
+    }
+}
+
+/// Draws every position within `bounds` with `cell`, one line per row.
+fn render_bounds(bounds: Option<Bounds>, cell: impl Fn([isize; 2]) -> char) -> String {
+    let Some(bounds) = bounds else {
+        return String::new();
+    };
+    (bounds.min[1]..=bounds.max[1])
+        .map(|y| {
+            (bounds.min[0]..=bounds.max[0])
+                .map(|x| cell([x, y]))
+                .collect()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Cells on an unbounded plane, stored in a hash map.
+/// The bounding box of the occupied cells is kept up to date on every change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<[isize; 2], T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        SparseGrid {
+            cells: HashMap::with_capacity(capacity),
+            bounds: None,
+        }
+    }
+
+    pub fn insert(&mut self, pos: [isize; 2], value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(pos),
+            None => self.bounds = Some(Bounds::new(pos)),
+        }
+        self.cells.insert(pos, value)
+    }
+
+    /// Removes a cell. The bounds are only recomputed if it was on their edge.
+    pub fn remove(&mut self, pos: [isize; 2]) -> Option<T> {
+        let removed = self.cells.remove(&pos)?;
+        if self.bounds.is_some_and(|bounds| bounds.on_edge(pos)) {
+            self.bounds = Bounds::of(self.cells.keys().copied());
+        }
+        Some(removed)
+    }
+
+    pub fn get(&self, pos: [isize; 2]) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: [isize; 2]) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: [isize; 2]) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The bounding box of all cells, `None` while the grid is empty.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Every cell, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ([isize; 2], &T)> {
+        self.cells.iter().map(|(pos, value)| (*pos, value))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = [isize; 2]> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// The cells within `region`, in no particular order.
+    pub fn region(&self, region: Bounds) -> Vec<([isize; 2], &T)> {
+        let Some(region) = self.bounds.and_then(|bounds| bounds.intersect(&region)) else {
+            return vec![];
+        };
+        // look up each position of a small region, rather than scanning every cell.
+        if region.area() < self.len() {
+            region
+                .positions()
+                .filter_map(|pos| Some((pos, self.get(pos)?)))
+                .collect()
+        } else {
+            self.iter()
+                .filter(|(pos, _)| region.contains(*pos))
+                .collect()
+        }
+    }
+
+    /// Draws the grid within its bounds, with `f` deciding the character of every position.
+    pub fn render(&self, f: impl Fn(Option<&T>) -> char) -> String {
+        render_bounds(self.bounds, |pos| f(self.get(pos)))
+    }
+}
+
+/// Cells on an unbounded plane, stored in a dense `Grid` that grows when a cell is inserted
+/// outside of it. Faster than a `SparseGrid` when the cells are packed closely together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseGrid<T> {
+    /// The position of the top left corner of `cells`.
+    origin: [isize; 2],
+    cells: Grid<Option<T>>,
+    len: usize,
+    bounds: Option<Bounds>,
+}
+
+impl<T: Clone> Default for DenseGrid<T> {
+    fn default() -> Self {
+        DenseGrid::new()
+    }
+}
+
+impl<T: Clone> DenseGrid<T> {
+    pub fn new() -> Self {
+        DenseGrid {
+            origin: [0, 0],
+            cells: Grid::new(0, 0, None),
+            len: 0,
+            bounds: None,
+        }
+    }
+
+    fn local(&self, pos: [isize; 2]) -> Option<[usize; 2]> {
+        self.cells
+            .checked([pos[0] - self.origin[0], pos[1] - self.origin[1]])
+    }
+
+    /// Reallocates the storage so that it covers `pos`, at least doubling it in the directions
+    /// it has to grow in.
+    fn grow(&mut self, pos: [isize; 2]) {
+        let size = [self.cells.width(), self.cells.height()].map(|size| size.max(8) as isize);
+        let mut min = self.origin;
+        let mut max = [
+            self.origin[0] + self.cells.width() as isize - 1,
+            self.origin[1] + self.cells.height() as isize - 1,
+        ];
+        for i in 0..2 {
+            if self.cells.width() == 0 || pos[i] < min[i] {
+                min[i] = pos[i] - size[i];
+            }
+            if self.cells.width() == 0 || pos[i] > max[i] {
+                max[i] = pos[i] + size[i];
+            }
+        }
+        let width = (max[0] - min[0] + 1) as usize;
+        let height = (max[1] - min[1] + 1) as usize;
+        let mut cells = Grid::new(width, height, None);
+        for local in self.cells.positions() {
+            if let Some(value) = self.cells[local].take() {
+                let pos = [
+                    local[0] as isize + self.origin[0] - min[0],
+                    local[1] as isize + self.origin[1] - min[1],
+                ];
+                cells[[pos[0] as usize, pos[1] as usize]] = Some(value);
+            }
+        }
+        self.origin = min;
+        self.cells = cells;
+    }
+
+    pub fn insert(&mut self, pos: [isize; 2], value: T) -> Option<T> {
+        if self.local(pos).is_none() {
+            self.grow(pos);
+        }
+        let local = self.local(pos).unwrap();
+        let previous = self.cells[local].replace(value);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(pos),
+            None => self.bounds = Some(Bounds::new(pos)),
+        }
+        previous
+    }
+
+    /// Removes a cell. The bounds are only recomputed if it was on their edge.
+    pub fn remove(&mut self, pos: [isize; 2]) -> Option<T> {
+        let local = self.local(pos)?;
+        let removed = self.cells[local].take()?;
+        self.len -= 1;
+        if self.bounds.is_some_and(|bounds| bounds.on_edge(pos)) {
+            self.bounds = Bounds::of(self.positions());
+        }
+        Some(removed)
+    }
+
+    pub fn get(&self, pos: [isize; 2]) -> Option<&T> {
+        self.cells[self.local(pos)?].as_ref()
+    }
+
+    pub fn get_mut(&mut self, pos: [isize; 2]) -> Option<&mut T> {
+        let local = self.local(pos)?;
+        self.cells[local].as_mut()
+    }
+
+    pub fn contains(&self, pos: [isize; 2]) -> bool {
+        self.get(pos).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The bounding box of all cells, `None` while the grid is empty.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Every cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ([isize; 2], &T)> {
+        let origin = self.origin;
+        self.cells.iter().filter_map(move |(local, value)| {
+            let pos = [local[0] as isize + origin[0], local[1] as isize + origin[1]];
+            Some((pos, value.as_ref()?))
+        })
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = [isize; 2]> + '_ {
+        self.iter().map(|(pos, _)| pos)
+    }
+
+    /// The cells within `region`, row by row.
+    pub fn region(&self, region: Bounds) -> Vec<([isize; 2], &T)> {
+        let Some(region) = self.bounds.and_then(|bounds| bounds.intersect(&region)) else {
+            return vec![];
+        };
+        region
+            .positions()
+            .filter_map(|pos| Some((pos, self.get(pos)?)))
+            .collect()
+    }
+
+    /// Draws the grid within its bounds, with `f` deciding the character of every position.
+    pub fn render(&self, f: impl Fn(Option<&T>) -> char) -> String {
+        render_bounds(self.bounds, |pos| f(self.get(pos)))
+    }
+}
+
+/// Integer types that can be used as point coordinates.
+pub trait Coord:
+    Copy + Ord + Hash + fmt::Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! coord {
+    ($($t:ty),*) => {
+        $(impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        })*
+    };
+}
+
+coord!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Distance between two coordinates, which also works for unsigned types.
+fn abs_diff<T: Coord>(a: T, b: T) -> T {
+    a.max(b) - a.min(b)
+}
+
+/// The sign of `value` as -1, 0 or 1.
+fn signum<T: Coord + Neg<Output = T>>(value: T) -> T {
+    match value.cmp(&T::ZERO) {
+        Ordering::Less => -T::ONE,
+        Ordering::Equal => T::ZERO,
+        Ordering::Greater => T::ONE,
+    }
+}
+
+/// A point, or a vector between two points, on a plane. `y` grows downwards like in a `Grid`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coord> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// The number of king moves between the points, diagonals counting as one step.
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    /// Each coordinate as -1, 0 or 1, giving the single step towards this vector.
+    pub fn signum(self) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        Point2::new(signum(self.x), signum(self.y))
+    }
+
+    /// The neighbouring point in `direction`.
+    pub fn step(self, direction: Direction) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        self + direction.offset()
+    }
+}
+
+/// A point, or a vector between two points, in space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x)
+            .max(abs_diff(self.y, other.y))
+            .max(abs_diff(self.z, other.z))
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbours6(self) -> [Self; 6]
+    where
+        T: Neg<Output = T>,
+    {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            Point3::new(-one, zero, zero),
+            Point3::new(one, zero, zero),
+            Point3::new(zero, -one, zero),
+            Point3::new(zero, one, zero),
+            Point3::new(zero, zero, -one),
+            Point3::new(zero, zero, one),
+        ]
+        .map(|delta| self + delta)
+    }
+}
+
+macro_rules! point_ops {
+    ($point:ident { $($field:ident),* }, $size:literal) => {
+        impl<T: Coord> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl<T: Coord> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        /// Scales every coordinate.
+        impl<T: Coord> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                $point { $($field: self.$field * factor),* }
+            }
+        }
+
+        impl<T: Coord + Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: Coord> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coord> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T> From<[T; $size]> for $point<T> {
+            fn from([$($field),*]: [T; $size]) -> Self {
+                $point { $($field),* }
+            }
+        }
+
+        impl<T> From<$point<T>> for [T; $size] {
+            fn from(point: $point<T>) -> Self {
+                [$(point.$field),*]
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { x, y }, 2);
+point_ops!(Point3 { x, y, z }, 3);
+
+/// One of the four orthogonal directions, in clockwise order starting at `Up`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Reads `U/R/D/L`, `^>v<` or the compass points `N/E/S/W`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | '^' | 'N' => Some(Direction::Up),
+            'R' | '>' | 'E' => Some(Direction::Right),
+            'D' | 'v' | 'S' => Some(Direction::Down),
+            'L' | '<' | 'W' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// The position in `ALL`, which is also the number of right turns from `Up`.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self.index() + 2) % 4]
+    }
+
+    /// The vector of a single step, with `Up` decreasing `y`.
+    pub fn offset<T: Coord + Neg<Output = T>>(self) -> Point2<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        match self {
+            Direction::Up => Point2::new(zero, -one),
+            Direction::Right => Point2::new(one, zero),
+            Direction::Down => Point2::new(zero, one),
+            Direction::Left => Point2::new(-one, zero),
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = SolveError;
+
+    fn try_from(c: char) -> Result<Self, SolveError> {
+        Direction::from_char(c).ok_or_else(|| format!("unrecognised direction '{}'", c).into())
+    }
+}
+
+impl FromStr for Direction {
+    type Err = SolveError;
+
+    fn from_str(s: &str) -> Result<Self, SolveError> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(format!("unrecognised direction \"{}\"", s).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |c| c.to_digit(10).ok_or("not a digit".into())).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let mut grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[[2, 1]], 6);
+        assert_eq!(grid.get([3, 0]), None);
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(grid.get_mut([usize::MAX, usize::MAX]), None);
+        *grid.get_mut([0, 1]).unwrap() = 0;
+        assert_eq!(grid.to_string(), "123\n056");
+
+        let e = Grid::parse("12\n345\n", Ok).unwrap_err();
+        assert_eq!(e.line, Some(2));
+        let e =
+            Grid::parse("12\n3x\n", |c| c.to_digit(10).ok_or("not a digit".into())).unwrap_err();
+        assert_eq!(e.line, Some(2));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789\n");
+        let values =
+            |positions: Vec<[usize; 2]>| positions.iter().map(|p| grid[*p]).collect::<Vec<u32>>();
+        assert_eq!(values(grid.neighbours4([0, 0]).collect()), [2, 4]);
+        assert_eq!(values(grid.neighbours4([1, 1]).collect()), [2, 6, 8, 4]);
+        assert_eq!(values(grid.neighbours8([2, 2]).collect()), [5, 6, 8]);
+        assert_eq!(values(grid.ray([0, 2], [1, -1]).collect()), [5, 3]);
+        assert_eq!(*grid.get_wrapping([-1, 3]), 3);
+        assert_eq!(grid.step([0, 0], [-1, 0]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty grid")]
+    fn test_wrap_empty() {
+        Grid::<u8>::new(0, 0, 0).wrap([1, 1]);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits("12\n34\n");
+        assert_eq!(grid.rows().collect::<Vec<&[u32]>>(), [[1, 2], [3, 4]]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<u32>>(), [2, 4]);
+        assert_eq!(grid.position(|d| *d == 3), Some([0, 1]));
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = digits("123\n456\n");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_cw().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_ccw().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(
+            grid.map(|d| d % 2 == 0)
+                .render(|even| if *even { '#' } else { '.' }),
+            ".#.\n#.#"
+        );
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert([2, -1], 'a');
+        grid.insert([-3, 4], 'b');
+        grid.insert([0, 0], 'c');
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: [-3, -1],
+                max: [2, 4]
+            })
+        );
+        assert_eq!(grid.bounds().unwrap().area(), 36);
+
+        grid.remove([0, 0]);
+        assert_eq!(grid.bounds().unwrap().area(), 36);
+        grid.remove([-3, 4]);
+        assert_eq!(grid.bounds(), Some(Bounds::new([2, -1])));
+        grid.remove([2, -1]);
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn test_region_and_render() {
+        let mut sparse = SparseGrid::new();
+        let mut dense = DenseGrid::new();
+        for pos in [[0, 0], [1, 1], [-1, 2], [40, -30]] {
+            sparse.insert(pos, ());
+            dense.insert(pos, ());
+        }
+        sparse.remove([40, -30]);
+        dense.remove([40, -30]);
+        let region = Bounds {
+            min: [0, 0],
+            max: [5, 5],
+        };
+        let mut found: Vec<[isize; 2]> = sparse.region(region).iter().map(|(p, _)| *p).collect();
+        found.sort();
+        assert_eq!(found, [[0, 0], [1, 1]]);
+        assert_eq!(dense.region(region).len(), 2);
+
+        let draw = |cell: Option<&()>| if cell.is_some() { '#' } else { '.' };
+        assert_eq!(sparse.render(draw), ".#.\n..#\n#..");
+        assert_eq!(dense.render(draw), sparse.render(draw));
+        assert_eq!(dense.len(), 3);
+        assert_eq!(dense.bounds(), sparse.bounds());
+    }
+
+    #[test]
+    fn test_points() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 4);
+        assert_eq!(a + b, Point2::new(-2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(a * 3, Point2::new(3, -6));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+        assert_eq!(Point2::<usize>::new(5, 1).manhattan(Point2::new(2, 3)), 5);
+
+        let c = Point3::new(1i8, 2, 3);
+        assert_eq!(c.manhattan(Point3::new(0, 0, 0)), 6);
+        assert_eq!(<[i8; 3]>::from(c), [1, 2, 3]);
+        assert!(c.neighbours6().iter().all(|n| n.manhattan(c) == 1));
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!("U".parse::<Direction>(), Ok(Direction::Up));
+        assert_eq!(Direction::try_from('<'), Ok(Direction::Left));
+        assert_eq!(Direction::from_char('S'), Some(Direction::Down));
+        assert!("X".parse::<Direction>().is_err());
+        assert!("UD".parse::<Direction>().is_err());
+
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Right.reverse(), Direction::Left);
+        assert_eq!(Point2::new(0, 0).step(Direction::Up), Point2::new(0, -1));
+        for (direction, delta) in Direction::ALL.iter().zip(NEIGHBOURS4) {
+            assert_eq!(<[isize; 2]>::from(direction.offset()), delta);
+        }
+    }
+}
@@ -0,0 +1,203 @@
+//! Searching for the best state reachable from a start by pruning hopeless branches.
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::thread;
+
+/// A maximisation problem, described by how to move between states and how good they are.
+pub trait Search {
+    type State: Clone + Eq + Hash;
+    type Score: Copy + Ord;
+
+    /// The states that can follow `state`. Every path of states must come to an end.
+    fn expand(&self, state: &Self::State) -> Vec<Self::State>;
+
+    /// What `state` is worth if the search stops there.
+    fn score(&self, state: &Self::State) -> Self::Score;
+
+    /// A score no state reachable from `state` can beat. The tighter it is, the more of the
+    /// search is pruned, but it must never be lower than the real best or answers will be wrong.
+    fn bound(&self, state: &Self::State) -> Self::Score;
+}
+
+/// Depth first search for the best score, skipping every state whose bound is no better than
+/// a score already found.
+///
+/// With `memoize` the search keeps every state it has seen, and skips it if it comes up again.
+/// With `parallel` each state following the start is searched on its own thread, at the cost
+/// of the threads not sharing their best scores or seen states.
+pub struct BranchAndBound<'a, P> {
+    problem: &'a P,
+    memoize: bool,
+    parallel: bool,
+}
+
+impl<'a, P: Search> BranchAndBound<'a, P> {
+    pub fn new(problem: &'a P) -> Self {
+        BranchAndBound {
+            problem,
+            memoize: false,
+            parallel: false,
+        }
+    }
+
+    pub fn memoize(mut self, memoize: bool) -> Self {
+        self.memoize = memoize;
+        self
+    }
+
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// The best score of any state reachable from `start`, including `start` itself.
+    pub fn run(&self, start: P::State) -> P::Score
+    where
+        P: Sync,
+        P::State: Send,
+        P::Score: Send,
+    {
+        let mut worker = self.worker(self.problem.score(&start));
+        if !self.parallel {
+            worker.visit(start);
+            return worker.best;
+        }
+        let floor = worker.best;
+        thread::scope(|scope| {
+            let threads: Vec<_> = self
+                .problem
+                .expand(&start)
+                .into_iter()
+                .map(|next| {
+                    let mut worker = self.worker(floor);
+                    scope.spawn(move || {
+                        worker.visit(next);
+                        worker.best
+                    })
+                })
+                .collect();
+            threads
+                .into_iter()
+                .map(|thread| thread.join().unwrap())
+                .fold(floor, Ord::max)
+        })
+    }
+
+    fn worker(&self, best: P::Score) -> Worker<'a, P> {
+        Worker {
+            problem: self.problem,
+            best,
+            seen: self.memoize.then(HashSet::new),
+        }
+    }
+}
+
+struct Worker<'a, P: Search> {
+    problem: &'a P,
+    best: P::Score,
+    seen: Option<HashSet<P::State>>,
+}
+
+impl<P: Search> Worker<'_, P> {
+    fn visit(&mut self, state: P::State) {
+        if self.problem.bound(&state) <= self.best {
+            return;
+        }
+        if let Some(seen) = &mut self.seen {
+            if !seen.insert(state.clone()) {
+                return;
+            }
+        }
+        self.best = self.best.max(self.problem.score(&state));
+        for next in self.problem.expand(&state) {
+            self.visit(next);
+        }
+    }
+}
+
+/// Breadth first search that only keeps the `width` states with the best bounds at each depth.
+/// Much faster than `BranchAndBound` on big problems, but may miss the best score.
+pub fn beam_search<P: Search>(problem: &P, start: P::State, width: usize) -> P::Score {
+    let mut best = problem.score(&start);
+    let mut beam = vec![start];
+    while !beam.is_empty() {
+        let mut next: Vec<P::State> = beam.iter().flat_map(|s| problem.expand(s)).collect();
+        next.sort_by_cached_key(|s| Reverse(problem.bound(s)));
+        next.truncate(width);
+        for state in &next {
+            best = best.max(problem.score(state));
+        }
+        beam = next;
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Picking items to fit in a knapsack, one decision per item.
+    struct Knapsack {
+        items: Vec<(u32, u32)>, // weight, value
+        capacity: u32,
+    }
+
+    #[derive(Clone, PartialEq, Eq, Hash)]
+    struct Packed {
+        next: usize,
+        weight: u32,
+        value: u32,
+    }
+
+    impl Search for Knapsack {
+        type State = Packed;
+        type Score = u32;
+
+        fn expand(&self, state: &Packed) -> Vec<Packed> {
+            let Some(&(weight, value)) = self.items.get(state.next) else {
+                return vec![];
+            };
+            let skip = Packed {
+                next: state.next + 1,
+                ..*state
+            };
+            if state.weight + weight > self.capacity {
+                return vec![skip];
+            }
+            let take = Packed {
+                next: state.next + 1,
+                weight: state.weight + weight,
+                value: state.value + value,
+            };
+            vec![take, skip]
+        }
+
+        fn score(&self, state: &Packed) -> u32 {
+            state.value
+        }
+
+        fn bound(&self, state: &Packed) -> u32 {
+            state.value + self.items[state.next..].iter().map(|i| i.1).sum::<u32>()
+        }
+    }
+
+    #[test]
+    fn test_branch_and_bound() {
+        let knapsack = Knapsack {
+            items: vec![(5, 10), (4, 40), (6, 30), (3, 50)],
+            capacity: 10,
+        };
+        let start = Packed {
+            next: 0,
+            weight: 0,
+            value: 0,
+        };
+        let search = BranchAndBound::new(&knapsack);
+        assert_eq!(search.run(start.clone()), 90);
+        let search = search.memoize(true).parallel(true);
+        assert_eq!(search.run(start.clone()), 90);
+        assert_eq!(beam_search(&knapsack, start.clone(), 100), 90);
+        assert!(beam_search(&knapsack, start, 1) <= 90);
+    }
+}
@@ -0,0 +1,116 @@
+//! Finding where a simulation starts repeating itself, to skip ahead to a far off step.
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// After `start` steps a simulation repeats itself every `period` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The first step that is in the same state as step `n`.
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+
+    /// The value at step `n` of a series that grows by the same amount every period, given its
+    /// values from step 0 until at least the end of the first period.
+    pub fn extrapolate(&self, values: &[i64], n: usize) -> i64 {
+        if n < values.len() {
+            return values[n];
+        }
+        let per_period = values[self.start + self.period] - values[self.start];
+        let periods = ((n - self.start) / self.period) as i64;
+        values[self.equivalent(n)] + periods * per_period
+    }
+}
+
+/// Watches the states of a simulation one step at a time until one comes up again.
+/// The key only needs to hold whatever decides how the simulation goes on from that step.
+#[derive(Debug, Clone)]
+pub struct CycleDetector<K> {
+    seen: HashMap<K, usize>,
+}
+
+impl<K: Eq + Hash> Default for CycleDetector<K> {
+    fn default() -> Self {
+        CycleDetector::new()
+    }
+}
+
+impl<K: Eq + Hash> CycleDetector<K> {
+    pub fn new() -> Self {
+        CycleDetector {
+            seen: HashMap::new(),
+        }
+    }
+
+    /// Records the key of the next step, counting from step 0.
+    /// Returns the cycle once the key has been seen at an earlier step.
+    pub fn push(&mut self, key: K) -> Option<Cycle> {
+        let step = self.seen.len();
+        match self.seen.get(&key) {
+            Some(&start) => Some(Cycle {
+                start,
+                period: step - start,
+            }),
+            None => {
+                self.seen.insert(key, step);
+                None
+            }
+        }
+    }
+}
+
+/// Runs `step` from `initial` until a state repeats. Never returns if the states never repeat.
+pub fn find_cycle<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut detector = CycleDetector::new();
+    let mut state = initial;
+    loop {
+        let next = step(&state);
+        if let Some(cycle) = detector.push(state) {
+            return cycle;
+        }
+        state = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        // 0, 1, 2, 3, 4, 2, 3, 4, ...
+        let cycle = find_cycle(0, |n| if *n == 4 { 2 } else { n + 1 });
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 2,
+                period: 3
+            }
+        );
+        assert_eq!(cycle.equivalent(1), 1);
+        assert_eq!(cycle.equivalent(5), 2);
+        assert_eq!(cycle.equivalent(10), 4);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // Grows by one until step 2, then by 10 every cycle of 3 steps
+        let values = [0, 1, 2, 5, 6, 12];
+        let cycle = Cycle {
+            start: 2,
+            period: 3,
+        };
+        assert_eq!(cycle.extrapolate(&values, 4), 6);
+        assert_eq!(cycle.extrapolate(&values, 8), 22);
+        assert_eq!(cycle.extrapolate(&values, 3002), 10002);
+    }
+}
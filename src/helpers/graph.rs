@@ -0,0 +1,120 @@
+//! Graphs cut down to the few nodes a puzzle cares about.
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::search::bfs_distances;
+
+/// The shortest distance between every pair of a set of interesting nodes in a larger graph,
+/// such as the valves worth opening in a network of tunnels.
+///
+/// Nodes are numbered in the order they were given, so a set of up to 64 of them fits in the
+/// bits of a `u64`.
+#[derive(Debug, Clone)]
+pub struct CompressedGraph<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    distances: Vec<Vec<Option<usize>>>,
+}
+
+impl<N: Clone + Eq + Hash> CompressedGraph<N> {
+    /// Keeps the nodes in `keep`, finding the distances between them with a breadth first search
+    /// from each over the full graph described by `neighbours`. Repeated nodes are kept once.
+    pub fn new<I>(keep: impl IntoIterator<Item = N>, mut neighbours: impl FnMut(&N) -> I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+    {
+        let mut nodes = vec![];
+        let mut index = HashMap::new();
+        for node in keep {
+            if !index.contains_key(&node) {
+                index.insert(node.clone(), nodes.len());
+                nodes.push(node);
+            }
+        }
+        let distances = nodes
+            .iter()
+            .map(|from| {
+                let reached = bfs_distances([from.clone()], &mut neighbours);
+                nodes.iter().map(|to| reached.get(to).copied()).collect()
+            })
+            .collect();
+        CompressedGraph {
+            nodes,
+            index,
+            distances,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn node(&self, i: usize) -> &N {
+        &self.nodes[i]
+    }
+
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn index(&self, node: &N) -> Option<usize> {
+        self.index.get(node).copied()
+    }
+
+    /// The number of steps from `from` to `to`, or `None` if it can't be reached.
+    pub fn distance(&self, from: usize, to: usize) -> Option<usize> {
+        self.distances[from][to]
+    }
+
+    /// Every other node that can be reached from `from`, with its distance.
+    pub fn neighbours(&self, from: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.distances[from]
+            .iter()
+            .enumerate()
+            .filter(move |(to, _)| *to != from)
+            .filter_map(|(to, distance)| Some((to, (*distance)?)))
+    }
+
+    /// The bit standing for node `i` in a `u64` set of nodes.
+    pub fn bit(&self, i: usize) -> u64 {
+        assert!(i < 64, "only the first 64 nodes fit in a mask");
+        1 << i
+    }
+
+    /// The mask with the bits of all the given nodes set.
+    pub fn mask(&self, nodes: impl IntoIterator<Item = usize>) -> u64 {
+        nodes.into_iter().fold(0, |mask, i| mask | self.bit(i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compressed_graph() {
+        // A line 0 - 1 - 2 - 3 - 4, and a lone node 5
+        let neighbours = |n: &u8| match n {
+            0 => vec![1],
+            4 => vec![3],
+            5 => vec![],
+            n => vec![n - 1, n + 1],
+        };
+        let graph = CompressedGraph::new([4, 0, 2, 5, 0], neighbours);
+        assert_eq!(graph.nodes(), &[4, 0, 2, 5]);
+        assert_eq!(graph.index(&2), Some(2));
+        assert_eq!(graph.index(&1), None);
+        assert_eq!(graph.distance(0, 1), Some(4));
+        assert_eq!(graph.distance(1, 2), Some(2));
+        assert_eq!(graph.distance(3, 0), None);
+        assert_eq!(
+            graph.neighbours(2).collect::<Vec<_>>(),
+            vec![(0, 2), (1, 2)]
+        );
+        assert_eq!(graph.mask([0, 2]), 0b101);
+    }
+}
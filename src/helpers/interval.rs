@@ -0,0 +1,175 @@
+//! Sets of integers stored as the ranges they cover, for when there are too many to list.
+use std::ops::RangeInclusive;
+
+use super::Coord;
+
+/// A set of integers kept as sorted inclusive ranges. Touching or overlapping ranges are
+/// merged, so every range is separated from the next by at least one missing value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Coord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+/// Whether a range ending at `end` is separated by a gap from one starting at `start`.
+fn before<T: Coord>(end: T, start: T) -> bool {
+    end < start && start - end > T::ONE
+}
+
+impl<T: Coord> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: vec![] }
+    }
+
+    /// Adds every value in `range`. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Ranges from `i` up to `j` touch the new one, and are replaced by it
+        let i = self.ranges.partition_point(|&(_, e)| before(e, start));
+        let j = self.ranges.partition_point(|&(s, _)| !before(end, s));
+        if i < j {
+            start = start.min(self.ranges[i].0);
+            end = end.max(self.ranges[j - 1].1);
+        }
+        self.ranges.splice(i..j, [(start, end)]);
+    }
+
+    /// Takes every value in `range` out of the set.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        *self = self.difference(&IntervalSet::from_iter([range]));
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges.iter().any(|&(s, e)| s <= value && value <= e)
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |total, &(s, e)| total + (e - s) + T::ONE)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The ranges making up the set, in increasing order.
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=e)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in other.ranges() {
+            result.insert(range);
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+            let (start, end) = (a.0.max(b.0), a.1.min(b.1));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // Whichever range ends first can't overlap anything further on
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// The values in this set that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let ranges = self
+            .ranges()
+            .flat_map(|range| other.gaps(range).ranges)
+            .collect();
+        IntervalSet { ranges }
+    }
+
+    /// The values within `bounds` that are missing from the set.
+    pub fn gaps(&self, bounds: RangeInclusive<T>) -> Self {
+        let (mut next, last) = bounds.into_inner();
+        let mut ranges = vec![];
+        if next > last {
+            return IntervalSet { ranges };
+        }
+        for &(s, e) in &self.ranges {
+            if e < next {
+                continue;
+            }
+            if s > last {
+                break;
+            }
+            if s > next {
+                ranges.push((next, s - T::ONE));
+            }
+            if e >= last {
+                return IntervalSet { ranges };
+            }
+            next = e + T::ONE;
+        }
+        ranges.push((next, last));
+        IntervalSet { ranges }
+    }
+}
+
+impl<T: Coord> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet<i32>) -> Vec<RangeInclusive<i32>> {
+        set.ranges().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut set = IntervalSet::from_iter([5..=7, 1..=2, 12..=14, 3..=3]);
+        assert_eq!(ranges(&set), vec![1..=3, 5..=7, 12..=14]);
+        assert_eq!(set.len(), 9);
+        set.insert(6..=12);
+        assert_eq!(ranges(&set), vec![1..=3, 5..=14]);
+        assert!(set.contains(9));
+        assert!(!set.contains(4));
+        set.remove(2..=10);
+        assert_eq!(ranges(&set), vec![1..=1, 11..=14]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = IntervalSet::from_iter([0..=10, 20..=30]);
+        let b = IntervalSet::from_iter([5..=25, 28..=40]);
+        assert_eq!(ranges(&a.union(&b)), vec![0..=40]);
+        assert_eq!(ranges(&a.intersection(&b)), vec![5..=10, 20..=25, 28..=30]);
+        assert_eq!(ranges(&a.difference(&b)), vec![0..=4, 26..=27]);
+        assert_eq!(ranges(&a.gaps(-5..=35)), vec![-5..=-1, 11..=19, 31..=35]);
+        assert_eq!(ranges(&a.gaps(2..=8)), vec![]);
+        assert!(IntervalSet::<u8>::new().is_empty());
+    }
+}
@@ -0,0 +1,160 @@
+//! Caching the results of recursive searches.
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::hash::Hash;
+
+/// Set to print the cache statistics of every `Memo::report`.
+pub const STATS_VAR: &str = "AOC_MEMO_STATS";
+
+/// How often a `Memo` already knew the answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl MemoStats {
+    /// The share of lookups that were answered from the cache, between 0 and 1.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64,
+        }
+    }
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate)",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0
+        )
+    }
+}
+
+/// Results of a function computed so far, keyed on its arguments.
+///
+/// `get_or_compute` hands the memo back to the function computing a missing value, so a
+/// recursive search can look up its sub-problems in the same cache:
+///
+/// ```
+/// use aoc::helpers::memo::Memo;
+///
+/// fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+///     memo.get_or_compute(n, |memo, &n| match n {
+///         0 | 1 => n,
+///         _ => fibonacci(n - 1, memo) + fibonacci(n - 2, memo),
+///     })
+/// }
+///
+/// let mut memo = Memo::new();
+/// assert_eq!(fibonacci(90, &mut memo), 2880067194370816120);
+/// assert_eq!(memo.stats().misses, 91);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    stats: MemoStats,
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo::new()
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            stats: MemoStats::default(),
+        }
+    }
+
+    /// The cached value for `key`, or the result of `compute`, which is cached for next time.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self, &K) -> V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = compute(self, &key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// The cached value for `key`, if there is one. Counts towards the statistics.
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.cache.get(key).cloned();
+        match value {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1,
+        }
+        value
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        self.cache.insert(key, value);
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Empties the cache. The statistics keep counting.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+
+    /// Prints the statistics to stderr under `name`, if `AOC_MEMO_STATS` is set.
+    pub fn report(&self, name: &str) {
+        if env::var_os(STATS_VAR).is_some() {
+            eprintln!("{}: {}, {} cached", name, self.stats, self.len());
+        }
+    }
+}
+
+/// Shorthand for `Memo::get_or_compute` that rebinds the memo and the key inside `body`.
+/// `memo!(memo, state => body)` caches `body` under `state`, and in `body` `state` is a
+/// reference to the key and `memo` can be passed on to recursive calls.
+#[macro_export]
+macro_rules! memo {
+    ($memo:ident, $key:ident => $body:expr) => {
+        $memo.get_or_compute($key, |$memo, $key| $body)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(grid: (u8, u8), memo: &mut Memo<(u8, u8), u64>) -> u64 {
+        crate::memo!(memo, grid => match *grid {
+            (0, _) | (_, 0) => 1,
+            (x, y) => paths((x - 1, y), memo) + paths((x, y - 1), memo),
+        })
+    }
+
+    #[test]
+    fn test_memo() {
+        let mut memo = Memo::new();
+        assert_eq!(paths((16, 16), &mut memo), 601080390);
+        let stats = memo.stats();
+        assert_eq!(stats.misses as usize, memo.len());
+        assert!(stats.hits > 0);
+        assert_eq!(
+            MemoStats { hits: 3, misses: 1 }.to_string(),
+            "3 hits, 1 misses (75.0% hit rate)"
+        );
+    }
+}
@@ -0,0 +1,188 @@
+//! Reading the block capital letters some puzzles draw instead of giving a number.
+use super::Grid;
+
+/// A set of letters drawn with `#` for lit pixels, all `height` rows tall. Every letter has
+/// at least one lit pixel in its first and last column.
+///
+/// Displays give each letter `pitch` columns. Most letters are narrower and leave a blank
+/// column before the next one, but some fill their columns and touch it.
+pub struct Font {
+    pub height: usize,
+    pub pitch: usize,
+    pub letters: &'static [(char, &'static [&'static str])],
+}
+
+/// The 4×6 font most puzzles use.
+pub const SMALL: Font = Font {
+    height: 6,
+    pitch: 5,
+    letters: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+/// The 6×10 font of the larger displays.
+#[rustfmt::skip]
+pub const LARGE: Font = Font {
+    height: 10,
+    pitch: 8,
+    letters: &[
+        ('A', &["..##..", ".#..#.", "#....#", "#....#", "#....#",
+                "######", "#....#", "#....#", "#....#", "#....#"]),
+        ('B', &["#####.", "#....#", "#....#", "#....#", "#####.",
+                "#....#", "#....#", "#....#", "#....#", "#####."]),
+        ('C', &[".####.", "#....#", "#.....", "#.....", "#.....",
+                "#.....", "#.....", "#.....", "#....#", ".####."]),
+        ('E', &["######", "#.....", "#.....", "#.....", "#####.",
+                "#.....", "#.....", "#.....", "#.....", "######"]),
+        ('F', &["######", "#.....", "#.....", "#.....", "#####.",
+                "#.....", "#.....", "#.....", "#.....", "#....."]),
+        ('G', &[".####.", "#....#", "#.....", "#.....", "#.....",
+                "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+        ('H', &["#....#", "#....#", "#....#", "#....#", "######",
+                "#....#", "#....#", "#....#", "#....#", "#....#"]),
+        ('J', &["...###", "....#.", "....#.", "....#.", "....#.",
+                "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+        ('K', &["#....#", "#...#.", "#..#..", "#.#...", "##....",
+                "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+        ('L', &["#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "#.....", "#.....", "#.....", "######"]),
+        ('N', &["#....#", "##...#", "##...#", "#.#..#", "#.#..#",
+                "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+        ('P', &["#####.", "#....#", "#....#", "#....#", "#####.",
+                "#.....", "#.....", "#.....", "#.....", "#....."]),
+        ('R', &["#####.", "#....#", "#....#", "#....#", "#####.",
+                "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+        ('X', &["#....#", "#....#", ".#..#.", ".#..#.", "..##..",
+                "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+        ('Z', &["######", ".....#", ".....#", "....#.", "...#..",
+                "..#...", ".#....", "#.....", "#.....", "######"]),
+    ],
+};
+
+impl Font {
+    /// The letter drawn on `screen` from column `start`, with its width. Wider letters are
+    /// tried first, so one that touches the next letter is not mistaken for a narrower one.
+    fn letter(&self, screen: &Grid<bool>, start: usize) -> Option<(char, usize)> {
+        self.letters
+            .iter()
+            .filter(|(_, rows)| {
+                start + rows[0].len() <= screen.width()
+                    && rows.iter().enumerate().all(|(y, row)| {
+                        row.bytes()
+                            .zip(start..)
+                            .all(|(pixel, x)| (pixel == b'#') == screen[[x, y]])
+                    })
+            })
+            .map(|&(letter, rows)| (letter, rows[0].len()))
+            .max_by_key(|&(_, width)| width)
+    }
+}
+
+/// Reads the letters drawn on `screen`, picking the font by its height. Returns `None` if the
+/// height matches neither font or anything on it is not a letter.
+pub fn read_letters(screen: &Grid<bool>) -> Option<String> {
+    let font = [SMALL, LARGE]
+        .into_iter()
+        .find(|font| font.height == screen.height())?;
+    let lit = |x: usize| screen.column(x).any(|&pixel| pixel);
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < screen.width() {
+        if !lit(x) {
+            x += 1;
+            continue;
+        }
+        let (letter, width) = font.letter(screen, x)?;
+        letters.push(letter);
+        x += width;
+    }
+    Some(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(rows: &[String]) -> Grid<bool> {
+        Grid::parse(&rows.join("\n"), |c| Ok(c == '#')).unwrap()
+    }
+
+    /// Draws `text` the way a puzzle would, giving each letter `pitch` columns.
+    fn draw(font: &Font, text: &str) -> Grid<bool> {
+        let rows: Vec<String> = (0..font.height)
+            .map(|y| {
+                text.chars()
+                    .map(|c| {
+                        let (_, rows) = font.letters.iter().find(|(l, _)| *l == c).unwrap();
+                        format!("{:.<1$}", rows[y], font.pitch)
+                    })
+                    .collect()
+            })
+            .collect();
+        screen(&rows)
+    }
+
+    #[test]
+    fn test_fonts() {
+        for font in [SMALL, LARGE] {
+            for (letter, rows) in font.letters {
+                assert_eq!(rows.len(), font.height, "{}", letter);
+                assert!(rows.iter().all(|row| row.len() == rows[0].len()));
+                assert!(rows.iter().any(|row| row.starts_with('#')), "{}", letter);
+                assert!(rows.iter().any(|row| row.ends_with('#')), "{}", letter);
+            }
+            let all: String = font.letters.iter().map(|(letter, _)| letter).collect();
+            assert_eq!(read_letters(&draw(&font, &all)), Some(all));
+        }
+    }
+
+    #[test]
+    fn test_read_letters() {
+        let rows = [
+            "#..#.####.###..###..",
+            "#..#.#....#..#.#..#.",
+            "####.###..#..#.###..",
+            "#..#.#....###..#..#.",
+            "#..#.#....#.#..#..#.",
+            "#..#.####.#..#.###..",
+        ]
+        .map(String::from);
+        assert_eq!(read_letters(&screen(&rows)), Some("HERB".to_string()));
+        assert_eq!(read_letters(&draw(&LARGE, "ZXN")), Some("ZXN".to_string()));
+
+        // I sits in the middle of its columns, and Y fills them and touches the next letter
+        let rows = [
+            ".###.#...#####",
+            "..#..#...##...",
+            "..#...#.#.###.",
+            "..#....#..#...",
+            "..#....#..#...",
+            ".###...#..####",
+        ]
+        .map(String::from);
+        assert_eq!(read_letters(&screen(&rows)), Some("IYE".to_string()));
+
+        let stripes = ["##..##", "###...", "####..", "#####.", "######", "#######"];
+        let stripes = screen(&stripes.map(|s| format!("{:.<8}", s)));
+        assert_eq!(read_letters(&stripes), None);
+        assert_eq!(read_letters(&draw(&SMALL, "AB").transpose()), None);
+    }
+}
@@ -0,0 +1,205 @@
+//! Reading puzzle input into typed values, with errors that point at the offending line.
+//!
+//! Errors carry a line number counted from the start of the text they were given, so they end
+//! up on the right line of the input when used inside `parse_lines` or `parse_blocks`.
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::SolveError;
+pub use crate::error::{parse_blocks, parse_lines};
+
+/// Parses `s` into any type that implements `FromStr`.
+pub fn value<T>(s: &str) -> Result<T, SolveError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    s.parse()
+        .map_err(|e| format!("invalid value \"{}\": {}", s, e).into())
+}
+
+/// Parses every item of a `separator` separated list, such as `79, 98`.
+pub fn list<T>(s: &str, separator: &str) -> Result<Vec<T>, SolveError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    s.split(separator).map(value).collect()
+}
+
+/// Every integer in `s`, ignoring whatever is around them. A `-` right before a number makes it
+/// negative unless it follows a letter or digit, so ranges like `2-4` are read as two numbers.
+pub fn integers<T>(s: &str) -> Result<Vec<T>, SolveError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let bytes = s.as_bytes();
+    let mut numbers = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let sign = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric());
+        if !sign && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        numbers.push(value(&s[start..i])?);
+    }
+    Ok(numbers)
+}
+
+/// Splits `line` into columns `width` characters wide. The last column may be shorter.
+pub fn columns(line: &str, width: usize) -> Vec<&str> {
+    let mut starts: Vec<usize> = line.char_indices().map(|(i, _)| i).step_by(width).collect();
+    starts.push(line.len());
+    starts.windows(2).map(|w| &line[w[0]..w[1]]).collect()
+}
+
+/// The line of `s` that byte `offset` is on, counting from 1.
+fn line_of(s: &str, offset: usize) -> usize {
+    s[..offset].matches('\n').count() + 1
+}
+
+/// Values that can be read from the fields `scan` picks out of a pattern.
+/// Implemented for tuples of up to eight types that implement `FromStr`.
+pub trait Fields: Sized {
+    const COUNT: usize;
+
+    /// Parses each field, given with its byte offset in the scanned text.
+    fn from_fields(s: &str, fields: &[(usize, &str)]) -> Result<Self, SolveError>;
+}
+
+macro_rules! fields {
+    ($count:literal; $($t:ident $i:tt),+) => {
+        impl<$($t),+> Fields for ($($t,)+)
+        where
+            $($t: FromStr, $t::Err: fmt::Display),+
+        {
+            const COUNT: usize = $count;
+
+            fn from_fields(s: &str, fields: &[(usize, &str)]) -> Result<Self, SolveError> {
+                Ok(($({
+                    let (offset, field) = fields[$i];
+                    value::<$t>(field).map_err(|e| e.at_line(line_of(s, offset)))?
+                },)+))
+            }
+        }
+    };
+}
+
+fields!(1; A 0);
+fields!(2; A 0, B 1);
+fields!(3; A 0, B 1, C 2);
+fields!(4; A 0, B 1, C 2, D 3);
+fields!(5; A 0, B 1, C 2, D 3, E 4);
+fields!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+fields!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+fields!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Reads the fields marked by `{}` out of `s`, which must otherwise match `pattern` exactly.
+/// Each field runs until the first place the text following it in the pattern matches.
+/// The pattern can span several lines.
+///
+/// ```
+/// use aoc::helpers::parse::scan;
+///
+/// let (x, y): (i32, i32) = scan("x={}, y={}", "x=-3, y=4").unwrap();
+/// assert_eq!((x, y), (-3, 4));
+/// ```
+pub fn scan<T: Fields>(pattern: &str, s: &str) -> Result<T, SolveError> {
+    let literals: Vec<&str> = pattern.split("{}").collect();
+    assert_eq!(
+        literals.len() - 1,
+        T::COUNT,
+        "pattern \"{}\" has the wrong number of fields",
+        pattern
+    );
+    let mismatch = |literal: &str, at: usize| {
+        let rest = s[at..].split("\n").next().unwrap_or("");
+        SolveError::new(format!("expected \"{}\" in \"{}\"", literal, rest)).at_line(line_of(s, at))
+    };
+
+    let mut at = literals[0].len();
+    if !s.starts_with(literals[0]) {
+        return Err(mismatch(literals[0], 0));
+    }
+    let mut fields = vec![];
+    for (i, literal) in literals.iter().enumerate().skip(1) {
+        let rest = &s[at..];
+        let end = if i == literals.len() - 1 {
+            rest.strip_suffix(literal)
+                .map(|field| field.len())
+                .ok_or_else(|| mismatch(literal, at))?
+        } else {
+            assert!(
+                !literal.is_empty(),
+                "pattern \"{}\" has two fields in a row",
+                pattern
+            );
+            rest.find(literal).ok_or_else(|| mismatch(literal, at))?
+        };
+        fields.push((at, &rest[..end]));
+        at += end + literal.len();
+    }
+    T::from_fields(s, &fields)
+}
+
+/// Scans every line of `input` with the same pattern.
+pub fn scan_lines<T: Fields>(input: &str, pattern: &str) -> Result<Vec<T>, SolveError> {
+    parse_lines(input, |line| scan(pattern, line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers() {
+        let line = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+        assert_eq!(integers::<i32>(line), Ok(vec![2, -18, -2, 15]));
+        assert_eq!(integers::<u32>("2-4,6-8"), Ok(vec![2, 4, 6, 8]));
+        assert_eq!(integers::<u8>("no numbers"), Ok(vec![]));
+        assert!(integers::<u8>("too big: 256").is_err());
+    }
+
+    #[test]
+    fn test_list_and_columns() {
+        assert_eq!(list::<u64>("79, 98", ", "), Ok(vec![79, 98]));
+        assert_eq!(columns("[Z] [M] [P]", 4), vec!["[Z] ", "[M] ", "[P]"]);
+    }
+
+    #[test]
+    fn test_scan() {
+        let moved: (usize, usize, usize) =
+            scan("move {} from {} to {}", "move 1 from 2 to 1").unwrap();
+        assert_eq!(moved, (1, 2, 1));
+        let (name, rate): (String, u32) =
+            scan("Valve {} has flow rate={}", "Valve AA has flow rate=0").unwrap();
+        assert_eq!((name.as_str(), rate), ("AA", 0));
+
+        let e = scan::<(u8,)>("move {}", "mv 1").unwrap_err();
+        assert_eq!(e.message, "expected \"move \" in \"mv 1\"");
+        let e = scan::<(u8, u8)>("x={}, y={}", "x=1, z=2").unwrap_err();
+        assert_eq!(e.message, "expected \", y=\" in \"1, z=2\"");
+    }
+
+    #[test]
+    fn test_scan_lines() {
+        let block = "Monkey 0:\n  Test: divisible by 23\n  If true: 2";
+        let pattern = "Monkey {}:\n  Test: divisible by {}\n  If true: {}";
+        let monkey: (u8, u64, u8) = scan(pattern, block).unwrap();
+        assert_eq!(monkey, (0, 23, 2));
+        let e = scan::<(u8, u64, u8)>(pattern, &block.replace("23", "x")).unwrap_err();
+        assert_eq!(e.line, Some(2));
+
+        let e = scan_lines::<(u8, u8)>("1-2\n3-4\n5+6\n", "{}-{}").unwrap_err();
+        assert_eq!(e.line, Some(3));
+    }
+}
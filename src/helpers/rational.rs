@@ -0,0 +1,152 @@
+//! Fractions that stay exact, for puzzles whose divisions don't always come out even.
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A fraction kept in lowest terms, with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: i128,
+    denom: i128,
+}
+
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numer: 0, denom: 1 };
+    pub const ONE: Rational = Rational { numer: 1, denom: 1 };
+
+    /// The fraction `numer / denom`. Panics if `denom` is zero.
+    pub fn new(numer: i128, denom: i128) -> Self {
+        assert!(denom != 0, "{}/0 has a zero denominator", numer);
+        let divisor = gcd(numer, denom) * denom.signum();
+        Rational {
+            numer: numer / divisor,
+            denom: denom / divisor,
+        }
+    }
+
+    pub fn numer(&self) -> i128 {
+        self.numer
+    }
+
+    pub fn denom(&self) -> i128 {
+        self.denom
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom == 1
+    }
+
+    /// The value as an integer, if it is one.
+    pub fn to_integer(&self) -> Option<i128> {
+        self.is_integer().then_some(self.numer)
+    }
+
+    /// `self / other`, or `None` if `other` is zero.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        (other.numer != 0).then(|| self / other)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational {
+            numer: n as i128,
+            denom: 1,
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Self) -> Self {
+        Rational::new(
+            self.numer * other.denom + other.numer * self.denom,
+            self.denom * other.denom,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Self) -> Self {
+        Rational::new(self.numer * other.numer, self.denom * other.denom)
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// Panics if `other` is zero. See `checked_div`.
+    fn div(self, other: Self) -> Self {
+        Rational::new(self.numer * other.denom, self.denom * other.numer)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Self {
+        Rational {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numer * other.denom).cmp(&(other.numer * self.denom))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.denom {
+            1 => write!(f, "{}", self.numer),
+            denom => write!(f, "{}/{}", self.numer, denom),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rational() {
+        let half = Rational::new(2, 4);
+        let third = Rational::new(-1, -3);
+        assert_eq!((half.numer(), half.denom()), (1, 2));
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(third - half, Rational::new(-1, 6));
+        assert_eq!((half * third).to_string(), "1/6");
+        assert_eq!(Rational::new(3, -1).to_string(), "-3");
+        assert_eq!((half / third).to_integer(), None);
+        assert_eq!((Rational::from(7) / half).to_integer(), Some(14));
+        assert_eq!(half.checked_div(Rational::ZERO), None);
+        assert!(third < half && -half < third);
+    }
+}
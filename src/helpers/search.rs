@@ -0,0 +1,240 @@
+//! Shortest path searches over graphs given as a neighbour closure.
+//! Every search takes any number of start nodes, which all begin at a cost of zero, and stops at
+//! the first node accepted by `is_goal`.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use super::Coord;
+
+/// The cheapest route found by a search, from one of the starts to the goal inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// Every node seen so far, with the cheapest known cost and the node it was reached from.
+/// Nodes are referred to by their index so the queues don't need to clone or order them.
+struct Visited<N, C> {
+    index: HashMap<N, usize>,
+    nodes: Vec<(N, C, Option<usize>)>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy + Ord> Visited<N, C> {
+    fn new() -> Self {
+        Visited {
+            index: HashMap::new(),
+            nodes: vec![],
+        }
+    }
+
+    /// Records reaching `node` at `cost`. Returns its index if this is the cheapest way so far.
+    fn reach(&mut self, node: N, cost: C, parent: Option<usize>) -> Option<usize> {
+        match self.index.get(&node) {
+            Some(&i) if self.nodes[i].1 <= cost => None,
+            Some(&i) => {
+                self.nodes[i].1 = cost;
+                self.nodes[i].2 = parent;
+                Some(i)
+            }
+            None => {
+                let i = self.nodes.len();
+                self.index.insert(node.clone(), i);
+                self.nodes.push((node, cost, parent));
+                Some(i)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<N, C> {
+        let mut nodes = vec![];
+        let mut current = Some(goal);
+        while let Some(i) = current {
+            nodes.push(self.nodes[i].0.clone());
+            current = self.nodes[i].2;
+        }
+        nodes.reverse();
+        Path {
+            cost: self.nodes[goal].1,
+            nodes,
+        }
+    }
+}
+
+/// Breadth first search for graphs where every step costs one.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        queue.extend(visited.reach(start, 0, None));
+    }
+    while let Some(i) = queue.pop_front() {
+        let (node, cost, _) = visited.nodes[i].clone();
+        if is_goal(&node) {
+            return Some(visited.path(i));
+        }
+        for next in neighbours(&node) {
+            queue.extend(visited.reach(next, cost + 1, Some(i)));
+        }
+    }
+    None
+}
+
+/// The number of steps from the nearest start to every node that can be reached.
+pub fn bfs_distances<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node] + 1;
+        for next in neighbours(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Dijkstra's algorithm, for graphs whose `neighbours` come with the cost of stepping to them.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Coord,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbours, |_| C::ZERO, is_goal)
+}
+
+/// A* search. `heuristic` estimates the remaining cost to a goal, and the path found is only
+/// guaranteed to be the cheapest if it never overestimates.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Coord,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited = Visited::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(i) = visited.reach(start, C::ZERO, None) {
+            queue.push(Reverse((estimate, C::ZERO, i)));
+        }
+    }
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        let node = visited.nodes[i].0.clone();
+        if cost > visited.nodes[i].1 {
+            // A cheaper way here was queued after this one
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(visited.path(i));
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(j) = visited.reach(next, next_cost, Some(i)) {
+                queue.push(Reverse((estimate, next_cost, j)));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::Grid;
+
+    fn maze() -> Grid<char> {
+        let maze = "S.#.\n..#E\n.##.\n....\n";
+        Grid::parse(maze, Ok).unwrap()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = maze();
+        let open = |p: &[usize; 2]| {
+            maze.neighbours4(*p)
+                .filter(|n| maze[*n] != '#')
+                .collect::<Vec<_>>()
+        };
+        let path = bfs([[0, 0]], open, |p| maze[*p] == 'E').unwrap();
+        assert_eq!(path.cost, 8);
+        assert_eq!(path.nodes.len(), 9);
+        assert_eq!(path.nodes.first(), Some(&[0, 0]));
+        assert_eq!(path.nodes.last(), Some(&[3, 1]));
+
+        // Starting from every cell in the left column only changes where the path begins
+        let path = bfs((0..4).map(|y| [0, y]), open, |p| maze[*p] == 'E').unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.nodes.first(), Some(&[0, 3]));
+
+        assert_eq!(bfs([[0, 0]], open, |_| false), None);
+
+        let distances = bfs_distances([[0, 0]], open);
+        assert_eq!(distances.len(), 12);
+        assert_eq!(distances[&[3, 1]], 8);
+        assert_eq!(distances[&[3, 0]], 9);
+    }
+
+    #[test]
+    fn test_weighted() {
+        // Going directly from 0 to 3 costs more than going the long way round
+        let edges = |n: &u8| match n {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 3)],
+            _ => vec![],
+        };
+        let path = dijkstra([0u8], edges, |n| *n == 3).unwrap();
+        assert_eq!(
+            path,
+            Path {
+                cost: 6,
+                nodes: vec![0, 1, 2, 3]
+            }
+        );
+
+        let heuristic = |n: &u8| 3 - *n as u32;
+        let path = astar(
+            [0u8],
+            |n| edges(n).into_iter().map(|(n, c)| (n, c as u32)),
+            heuristic,
+            |n| *n == 3,
+        );
+        assert_eq!(path.map(|p| p.cost), Some(6));
+    }
+}
@@ -0,0 +1,244 @@
+//! Running programs written in the small assembly languages some puzzles make up.
+use std::collections::HashSet;
+use std::fmt::{self, Write};
+
+/// One instruction of a puzzle's instruction set.
+pub trait Instruction: fmt::Debug {
+    /// How many cycles the instruction takes. It takes effect at the end of the last one.
+    fn cycles(&self) -> u64;
+
+    /// Applies the instruction to the registers. Returns how far to jump from this instruction,
+    /// or `None` to carry on with the next one.
+    fn execute(&self, registers: &mut [i64]) -> Option<isize>;
+}
+
+/// Why `Vm::run` returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The instruction pointer left the program.
+    Halted,
+    /// The instruction at this position is about to start.
+    Breakpoint(usize),
+}
+
+/// An instruction that finished, as recorded in the trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Executed {
+    cycle: u64,
+    ip: usize,
+    registers: Vec<i64>,
+}
+
+/// A machine running `program` one cycle at a time.
+///
+/// Observers passed to `run` or `step` see the machine during every cycle, before the
+/// instruction finishing in that cycle has taken effect. `clock` is then the number of the
+/// current cycle, counting from 1.
+pub struct Vm<'a, I> {
+    program: &'a [I],
+    registers: Vec<i64>,
+    ip: usize,
+    clock: u64,
+    /// Cycles spent so far on the current instruction.
+    elapsed: u64,
+    breakpoints: HashSet<usize>,
+    /// The breakpoint `run` last stopped at, which it carries on past when called again.
+    paused_at: Option<usize>,
+    trace: Option<Vec<Executed>>,
+}
+
+impl<'a, I: Instruction> Vm<'a, I> {
+    pub fn new(program: &'a [I], registers: Vec<i64>) -> Self {
+        Vm {
+            program,
+            registers,
+            ip: 0,
+            clock: 0,
+            elapsed: 0,
+            breakpoints: HashSet::new(),
+            paused_at: None,
+            trace: None,
+        }
+    }
+
+    /// Makes `run` stop before the instruction at `ip` starts.
+    pub fn breakpoint(mut self, ip: usize) -> Self {
+        self.breakpoints.insert(ip);
+        self
+    }
+
+    /// Records every instruction that finishes, for `dump_trace`.
+    pub fn trace(mut self, trace: bool) -> Self {
+        self.trace = trace.then(Vec::new);
+        self
+    }
+
+    pub fn registers(&self) -> &[i64] {
+        &self.registers
+    }
+
+    pub fn register(&self, register: usize) -> i64 {
+        self.registers[register]
+    }
+
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn clock(&self) -> u64 {
+        self.clock
+    }
+
+    pub fn running(&self) -> bool {
+        self.ip < self.program.len()
+    }
+
+    /// Runs a single cycle, calling `observer` during it. Returns the number of the cycle, or
+    /// `None` if the program has already halted.
+    pub fn step(&mut self, observer: &mut impl FnMut(&Self)) -> Option<u64> {
+        let instruction = self.program.get(self.ip)?;
+        self.paused_at = None;
+        self.clock += 1;
+        observer(self);
+        self.elapsed += 1;
+        if self.elapsed < instruction.cycles() {
+            return Some(self.clock);
+        }
+        self.elapsed = 0;
+        let jump = instruction.execute(&mut self.registers);
+        if let Some(trace) = &mut self.trace {
+            trace.push(Executed {
+                cycle: self.clock,
+                ip: self.ip,
+                registers: self.registers.clone(),
+            });
+        }
+        // Jumping before the start halts the program, the same as jumping past the end
+        self.ip = self
+            .ip
+            .checked_add_signed(jump.unwrap_or(1))
+            .unwrap_or(usize::MAX);
+        Some(self.clock)
+    }
+
+    /// Runs until the program halts or reaches a breakpoint, calling `observer` during every
+    /// cycle. Calling `run` again after it stopped at a breakpoint carries on past it.
+    pub fn run(&mut self, mut observer: impl FnMut(&Self)) -> Stop {
+        while self.running() {
+            if self.elapsed == 0
+                && self.breakpoints.contains(&self.ip)
+                && self.paused_at != Some(self.ip)
+            {
+                self.paused_at = Some(self.ip);
+                return Stop::Breakpoint(self.ip);
+            }
+            self.step(&mut observer);
+        }
+        Stop::Halted
+    }
+
+    /// Every instruction that finished since tracing was turned on, one per line with the cycle
+    /// it finished in, its position and the registers after it.
+    pub fn dump_trace(&self) -> String {
+        let mut dump = String::new();
+        for executed in self.trace.iter().flatten() {
+            let instruction = format!("{:?}", self.program[executed.ip]);
+            writeln!(
+                dump,
+                "{:>6} {:>4}  {:<20} {:?}",
+                executed.cycle, executed.ip, instruction, executed.registers
+            )
+            .unwrap();
+        }
+        dump
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    enum Asm {
+        Set(usize, i64),
+        Add(usize, usize),
+        Dec(usize),
+        Jnz(usize, isize),
+    }
+
+    impl Instruction for Asm {
+        fn cycles(&self) -> u64 {
+            match self {
+                Asm::Set(..) | Asm::Dec(_) => 1,
+                Asm::Add(..) => 2,
+                Asm::Jnz(..) => 3,
+            }
+        }
+
+        fn execute(&self, registers: &mut [i64]) -> Option<isize> {
+            match *self {
+                Asm::Set(r, value) => registers[r] = value,
+                Asm::Add(r, other) => registers[r] += registers[other],
+                Asm::Dec(r) => registers[r] -= 1,
+                Asm::Jnz(r, offset) => return (registers[r] != 0).then_some(offset),
+            }
+            None
+        }
+    }
+
+    /// Multiplies 3 by 4 by repeated addition.
+    const PROGRAM: [Asm; 5] = [
+        Asm::Set(1, 3),
+        Asm::Set(2, 4),
+        Asm::Add(0, 2),
+        Asm::Dec(1),
+        Asm::Jnz(1, -2),
+    ];
+
+    #[test]
+    fn test_run() {
+        let mut vm = Vm::new(&PROGRAM, vec![0; 3]);
+        let mut seen = vec![];
+        assert_eq!(
+            vm.run(|vm| seen.push((vm.clock(), vm.register(0)))),
+            Stop::Halted
+        );
+        assert_eq!(vm.registers(), [12, 0, 4]);
+        assert_eq!(vm.clock(), 20);
+        assert_eq!(seen.len(), 20);
+        // Additions only show in the cycle after they finish
+        assert_eq!(seen[3..6], [(4, 0), (5, 4), (6, 4)]);
+    }
+
+    #[test]
+    fn test_breakpoints_and_trace() {
+        let mut vm = Vm::new(&PROGRAM, vec![0; 3]).breakpoint(3).trace(true);
+        for (clock, product) in [(4, 4), (10, 8), (16, 12)] {
+            assert_eq!(vm.run(|_| {}), Stop::Breakpoint(3));
+            assert_eq!((vm.clock(), vm.register(0)), (clock, product));
+        }
+        assert_eq!(vm.run(|_| {}), Stop::Halted);
+        assert_eq!(vm.step(&mut |_| {}), None);
+        assert_eq!(vm.clock(), 20);
+
+        let dump = vm.dump_trace();
+        assert_eq!(dump.lines().count(), 11);
+        assert_eq!(
+            dump.lines().nth(2),
+            Some("     4    2  Add(0, 2)            [4, 3, 4]")
+        );
+    }
+
+    #[test]
+    fn test_breakpoint_at_start() {
+        let mut vm = Vm::new(&PROGRAM, vec![0; 3]).breakpoint(0).breakpoint(2);
+        assert_eq!(vm.run(|_| {}), Stop::Breakpoint(0));
+        assert_eq!(vm.clock(), 0);
+        assert_eq!(vm.run(|_| {}), Stop::Breakpoint(2));
+        assert_eq!(vm.clock(), 2);
+        // Stepping off a breakpoint by hand means the next run stops when it comes round again
+        assert_eq!(vm.step(&mut |_| {}), Some(3));
+        assert_eq!(vm.run(|_| {}), Stop::Breakpoint(2));
+        assert_eq!((vm.clock(), vm.register(0)), (8, 4));
+    }
+}
@@ -0,0 +1,317 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::process::Command;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::PartResult;
+
+/// Timings of every `cargo all` run, appended one row per solved part.
+pub const HISTORY_PATH: &str = "bench_history.csv";
+pub const HISTORY_HEADER: &str = "run,commit,profile,year,day,part,elapsed_ns";
+
+/// One row of the history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub run: u64,
+    pub commit: String,
+    pub profile: String,
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub elapsed: Duration,
+}
+
+impl Entry {
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.run,
+            self.commit,
+            self.profile,
+            self.year,
+            self.day,
+            self.part,
+            self.elapsed.as_nanos()
+        )
+    }
+
+    pub fn from_csv(line: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = line.split(",").collect();
+        if fields.len() != 7 {
+            return Err(format!("expected 7 fields, got {}", fields.len()));
+        }
+        fn number<T: FromStr>(field: &str) -> Result<T, String>
+        where
+            T::Err: fmt::Display,
+        {
+            field
+                .parse()
+                .map_err(|e| format!("invalid number \"{}\": {}", field, e))
+        }
+        Ok(Entry {
+            run: number(fields[0])?,
+            commit: fields[1].to_string(),
+            profile: fields[2].to_string(),
+            year: number(fields[3])?,
+            day: number(fields[4])?,
+            part: number(fields[5])?,
+            elapsed: Duration::from_nanos(number(fields[6])?),
+        })
+    }
+}
+
+/// The short hash of `HEAD`, suffixed with `-dirty` if the tree has uncommitted changes.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain"]) {
+            Some(status) if !status.is_empty() => format!("{}-dirty", hash),
+            _ => hash,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+pub fn current_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+/// Appends the solved parts of one run to the history file.
+/// Benchmarked runs store the median solve time of each part under their own profile,
+/// so they are only ever compared with other benchmarked runs.
+pub fn append(results: &[PartResult], benchmarked: bool) -> io::Result<()> {
+    if results.is_empty() {
+        return Ok(());
+    }
+    let run = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let commit = current_commit();
+    let profile = match benchmarked {
+        true => format!("{}-bench", current_profile()),
+        false => current_profile().to_string(),
+    };
+    let new_file = fs::metadata(HISTORY_PATH).is_err();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY_PATH)?;
+    if new_file {
+        writeln!(file, "{}", HISTORY_HEADER)?;
+    }
+    for result in results.iter().filter(|result| result.answer.is_some()) {
+        let entry = Entry {
+            run,
+            commit: commit.clone(),
+            profile: profile.clone(),
+            year: result.year,
+            day: result.day,
+            part: result.part,
+            elapsed: result.elapsed,
+        };
+        writeln!(file, "{}", entry.to_csv())?;
+    }
+    Ok(())
+}
+
+/// Per-day timings of one recorded run, keyed by year and day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub id: u64,
+    pub commit: String,
+    pub profile: String,
+    pub days: BTreeMap<(i16, u8), Duration>,
+}
+
+/// Loads the history file and groups its rows into runs, oldest first.
+pub fn load() -> Result<Vec<Run>, String> {
+    let contents = match fs::read_to_string(HISTORY_PATH) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(format!("{}: {}", HISTORY_PATH, e)),
+    };
+    let mut entries = vec![];
+    for (i, line) in contents.split("\n").enumerate() {
+        if line.is_empty() || line == HISTORY_HEADER {
+            continue;
+        }
+        let entry =
+            Entry::from_csv(line).map_err(|e| format!("{}:{}: {}", HISTORY_PATH, i + 1, e))?;
+        entries.push(entry);
+    }
+    Ok(group_runs(entries))
+}
+
+pub fn group_runs(entries: Vec<Entry>) -> Vec<Run> {
+    let mut runs: Vec<Run> = vec![];
+    for entry in entries {
+        let same_run = runs.last().is_some_and(|run| {
+            run.id == entry.run && run.commit == entry.commit && run.profile == entry.profile
+        });
+        if !same_run {
+            runs.push(Run {
+                id: entry.run,
+                commit: entry.commit,
+                profile: entry.profile,
+                days: BTreeMap::new(),
+            });
+        }
+        *runs
+            .last_mut()
+            .unwrap()
+            .days
+            .entry((entry.year, entry.day))
+            .or_default() += entry.elapsed;
+    }
+    runs
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Slower,
+    Faster,
+    Unchanged,
+}
+
+/// How one day's time changed between a baseline run and the current one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub year: i16,
+    pub day: u8,
+    pub before: Duration,
+    pub after: Duration,
+    /// `None` when the baseline took no measurable time, so there is nothing to compare against.
+    pub percent: Option<f64>,
+    pub trend: Trend,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let trend = match self.trend {
+            Trend::Slower => "🔺 slower",
+            Trend::Faster => "🔻 faster",
+            Trend::Unchanged => "unchanged",
+        };
+        let percent = match self.percent {
+            Some(percent) => format!("{:+.1}%", percent),
+            None => "n/a".to_string(),
+        };
+        write!(
+            f,
+            "{} Day {:02}: {:.2?} -> {:.2?} ({}) {}",
+            self.year, self.day, self.before, self.after, percent, trend
+        )
+    }
+}
+
+/// Compares the days present in both runs.
+/// A day counts as slower or faster once its time changed by more than `threshold` percent.
+pub fn compare(baseline: &Run, current: &Run, threshold: f64) -> Vec<Change> {
+    current
+        .days
+        .iter()
+        .filter_map(|(&(year, day), &after)| {
+            let before = *baseline.days.get(&(year, day))?;
+            let percent = (!before.is_zero())
+                .then(|| (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0);
+            let trend = match percent {
+                Some(percent) if percent > threshold => Trend::Slower,
+                Some(percent) if percent < -threshold => Trend::Faster,
+                _ => Trend::Unchanged,
+            };
+            Some(Change {
+                year,
+                day,
+                before,
+                after,
+                percent,
+                trend,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(run: u64, day: u8, part: u8, micros: u64) -> Entry {
+        Entry {
+            run,
+            commit: format!("c{}", run),
+            profile: "release".to_string(),
+            year: 2022,
+            day,
+            part,
+            elapsed: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let entry = entry(1670000000, 16, 2, 1500);
+        assert_eq!(
+            entry.to_csv(),
+            "1670000000,c1670000000,release,2022,16,2,1500000"
+        );
+        assert_eq!(Entry::from_csv(&entry.to_csv()), Ok(entry));
+        assert!(Entry::from_csv("1,abc,release,16").is_err());
+        assert!(Entry::from_csv("1,abc,release,2022,300,1,5").is_err());
+        assert!(Entry::from_csv("1,abc,release,70000,1,1,5").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let runs = group_runs(vec![
+            entry(1, 1, 1, 100),
+            entry(1, 1, 2, 100),
+            entry(1, 16, 1, 1000),
+            entry(1, 17, 1, 1000),
+            entry(1, 19, 1, 0),
+            entry(2, 1, 1, 100),
+            entry(2, 1, 2, 90),
+            entry(2, 16, 1, 1500),
+            entry(2, 17, 1, 500),
+            entry(2, 18, 1, 500),
+            entry(2, 19, 1, 50),
+        ]);
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].days[&(2022, 1)], Duration::from_micros(200));
+
+        let trends: Vec<(u8, Trend)> = compare(&runs[0], &runs[1], 10.0)
+            .iter()
+            .map(|change| (change.day, change.trend))
+            .collect();
+        assert_eq!(
+            trends,
+            vec![
+                (1, Trend::Unchanged),
+                (16, Trend::Slower),
+                (17, Trend::Faster),
+                (19, Trend::Unchanged)
+            ]
+        );
+        let changes = compare(&runs[0], &runs[1], 10.0);
+        assert_eq!(changes[3].percent, None);
+        assert!(changes[3].to_string().contains("(n/a)"));
+    }
+}
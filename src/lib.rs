@@ -3,123 +3,291 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
-use std::env;
+use std::fmt::Display;
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+use std::{env, io};
 
+use error::SolveError;
+use params::Params;
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod error;
 pub mod helpers;
+pub mod history;
+pub mod output;
+pub mod params;
+pub mod puzzle;
+pub mod submit;
+pub mod years;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use aoc::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
+/// The year `--year` defaults to.
+pub const DEFAULT_YEAR: i16 = 2022;
+
+/// A single day's puzzle.
+/// `parse` turns the raw input into `Input` once, and both parts then work from a reference to it.
+/// Malformed input is reported as a `SolveError` rather than a panic. A part returns `None` when
+/// it has no answer, and parts that can tell why override `try_part_one` or `try_part_two`.
+///
+/// Puzzles whose text gives values that differ between the examples and the real input read
+/// them from `Params` in `parse_with`, and `parse` then uses the values for the real input.
+pub trait Solution {
+    const YEAR: i16;
+    const DAY: u8;
+
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError>;
+    fn part_one(input: &Self::Input) -> Option<Self::PartOne>;
+    fn part_two(input: &Self::Input) -> Option<Self::PartTwo>;
+
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Input, SolveError> {
+        Self::parse(input)
+    }
+
+    /// Like `part_one`, but fails with the reason the input can't be solved. This is what the
+    /// runner calls.
+    fn try_part_one(input: &Self::Input) -> Result<Option<Self::PartOne>, SolveError> {
+        Ok(Self::part_one(input))
+    }
+
+    /// Like `part_two`, but fails with the reason the input can't be solved. This is what the
+    /// runner calls.
+    fn try_part_two(input: &Self::Input) -> Result<Option<Self::PartTwo>, SolveError> {
+        Ok(Self::part_two(input))
+    }
+}
+
+/// The outcome of running one part of a solution.
+/// `elapsed` covers parsing and solving, but not reading the input file.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+}
+
+impl PartResult {
+    pub fn unsolved(year: i16, day: u8, part: u8) -> Self {
+        PartResult {
+            year,
+            day,
+            part,
+            answer: None,
+            elapsed: Duration::ZERO,
         }
+    }
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+fn time_part<S: Solution, T: Display>(
+    part: u8,
+    input: &str,
+    params: &Params,
+    solver: fn(&S::Input) -> Result<Option<T>, SolveError>,
+) -> Result<PartResult, SolveError> {
+    let timer = Instant::now();
+    let in_day = |e: SolveError| e.in_day(S::YEAR, S::DAY, Some(part));
+    let parsed = S::parse_with(input, params).map_err(in_day)?;
+    let result = solver(&parsed).map_err(in_day)?;
+    let elapsed = timer.elapsed();
+    Ok(PartResult {
+        year: S::YEAR,
+        day: S::DAY,
+        part,
+        answer: result.map(|r| r.to_string()),
+        elapsed,
+    })
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+fn solve_parts<S: Solution>(input: &str, params: &Params) -> Result<[PartResult; 2], SolveError> {
+    Ok([
+        time_part::<S, _>(1, input, params, S::try_part_one)?,
+        time_part::<S, _>(2, input, params, S::try_part_two)?,
+    ])
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// Type-erased handle to a `Solution`, so that days with different input and output types
+/// can be stored side by side and run in-process.
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub year: i16,
+    pub day: u8,
+    solve: fn(&str, &Params) -> Result<[PartResult; 2], SolveError>,
+    bench: fn(&str, &Params, bench::BenchConfig) -> Result<bench::BenchReport, SolveError>,
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+impl Solver {
+    pub fn new<S: Solution>() -> Self {
+        Solver {
+            year: S::YEAR,
+            day: S::DAY,
+            solve: solve_parts::<S>,
+            bench: bench::bench_parts::<S>,
+        }
+    }
+
+    pub fn solve(&self, input: &str, params: &Params) -> Result<[PartResult; 2], SolveError> {
+        (self.solve)(input, params)
+    }
+
+    pub fn bench(
+        &self,
+        input: &str,
+        params: &Params,
+        config: bench::BenchConfig,
+    ) -> Result<bench::BenchReport, SolveError> {
+        (self.bench)(input, params, config)
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// The flags `cargo solve` takes besides the day and `--year`.
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    pub format: output::OutputFormat,
+    pub mode: answers::AnswerMode,
+    pub bench: Option<bench::BenchConfig>,
+    /// Run against the example rather than the real input.
+    pub example: bool,
+    /// Set with `--param`, on top of the parameters stored with the input.
+    pub params: Params,
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+impl RunOptions {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let mode = answers::AnswerMode::from_args(args)?;
+        let example = args.contains("--example");
+        if example && mode != answers::AnswerMode::Ignore {
+            return Err(invalid_arg(
+                "--example",
+                "cannot be used with --verify or --record",
+            ));
+        }
+        Ok(RunOptions {
+            format: output::OutputFormat::from_args(args)?,
+            mode,
+            bench: bench::BenchConfig::from_args(args)?,
+            example,
+            params: Params::from_args(args)?,
+        })
+    }
+}
+
+/// An error for an argument that was read but can't be used, explaining why.
+pub fn invalid_arg(value: impl Into<String>, cause: &str) -> pico_args::Error {
+    pico_args::Error::Utf8ArgumentParsingFailed {
+        value: value.into(),
+        cause: cause.to_string(),
+    }
+}
+
+/// Fails with the first argument that was not read, such as a misspelt flag.
+pub fn finish_args(args: pico_args::Arguments) -> Result<(), pico_args::Error> {
+    match args.finish().first() {
+        Some(arg) => Err(invalid_arg(arg.to_string_lossy(), "unexpected argument")),
+        None => Ok(()),
+    }
+}
+
+/// Runs one day against its real input, as `cargo solve` does.
+/// With `--example` it runs against the example instead, using the parameters stored with it.
+pub fn run(solver: Solver, options: RunOptions) {
+    let RunOptions {
+        format,
+        mode,
+        bench,
+        example,
+        params: overrides,
+    } = options;
+    let folder = if example { "examples" } else { "inputs" };
+    let input = read_file(folder, solver.year, solver.day);
+    let params = match Params::load(folder, solver.year, solver.day) {
+        Ok(mut params) => {
+            params.merge(&overrides);
+            params
+        }
+        Err(e) => {
+            eprintln!("Failed to load parameters: {}", e);
+            process::exit(1);
+        }
+    };
+    let exit_with_error = |e: SolveError| -> ! {
+        eprintln!("Failed to solve {}", e);
+        process::exit(1);
+    };
+    let results = match bench {
+        Some(config) => {
+            let report = solver
+                .bench(&input, &params, config)
+                .unwrap_or_else(|e| exit_with_error(e));
+            format.print_bench_header();
+            format.print_bench(&report);
+            report.results()
+        }
+        None => {
+            let results = solver
+                .solve(&input, &params)
+                .unwrap_or_else(|e| exit_with_error(e));
+            format.print_header();
+            for result in &results {
+                format.print_result(result);
             }
+            results
         }
-    })
+    };
+    if !mode.apply(solver.year, solver.day, &results) {
+        process::exit(1);
+    }
+}
+
+/// The path of `src/<folder>/<year>/NN.<extension>` relative to the working directory.
+pub fn file_path(folder: &str, year: i16, day: u8, extension: &str) -> io::Result<PathBuf> {
+    let cwd = env::current_dir()?;
+
+    Ok(cwd
+        .join("src")
+        .join(folder)
+        .join(year.to_string())
+        .join(format!("{:02}.{}", day, extension)))
+}
+
+pub fn try_read_file(folder: &str, year: i16, day: u8) -> io::Result<String> {
+    fs::read_to_string(file_path(folder, year, day, "txt")?)
+}
+
+pub fn read_file(folder: &str, year: i16, day: u8) -> String {
+    try_read_file(folder, year, day).expect("could not open input file")
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+/// Reads and parses a day's file from `src/<folder>` along with the parameters stored next to it,
+/// the same way `cargo solve` does.
+pub fn parse_file<S: Solution>(folder: &str) -> Result<S::Input, SolveError> {
+    let params = Params::load(folder, S::YEAR, S::DAY)?;
+    S::parse_with(&read_file(folder, S::YEAR, S::DAY), &params)
+}
+
+/// Writes `contents` to a temporary file next to `path` and renames it into place,
+/// so an interrupted write never leaves a truncated file behind. Missing parent folders are created.
+pub fn write_file_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
     }
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_path);
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp_path);
+    })
 }
@@ -2,45 +2,129 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use aoc::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use aoc::bench::BenchConfig;
+use aoc::history;
+use aoc::output::OutputFormat;
+use aoc::params::Params;
+use aoc::{PartResult, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::process;
+use std::time::Duration;
+
+struct Args {
+    /// Without `--year`, every registered year is run.
+    year: Option<i16>,
+    format: OutputFormat,
+    bench: Option<BenchConfig>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        format: OutputFormat::from_args(&mut args)?,
+        bench: BenchConfig::from_args(&mut args)?,
+    };
+    aoc::finish_args(args)?;
+    Ok(parsed)
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+    let Args {
+        year,
+        format,
+        bench,
+    } = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    let pretty = format == OutputFormat::Pretty;
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+    match bench {
+        Some(_) => format.print_bench_header(),
+        None => format.print_header(),
+    }
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+    let mut total = Duration::ZERO;
+    let mut recorded = vec![];
+    let mut failed = false;
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+    let solvers = aoc::years::all()
+        .into_iter()
+        .filter(|solver| year.is_none_or(|year| solver.year == year));
 
+    for solver in solvers {
+        if pretty {
+            println!("---------------");
             println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
+                "{}| {} Day {:02} |{}",
+                ANSI_BOLD, solver.year, solver.day, ANSI_RESET
             );
+            println!("---------------");
+        }
 
-            if is_empty {
-                0_f64
-            } else {
-                aoc::parse_exec_time(&output)
+        let input = match aoc::try_read_file("inputs", solver.year, solver.day) {
+            Ok(input) => input,
+            Err(_) => {
+                if pretty {
+                    println!("Not solved.");
+                } else if bench.is_none() {
+                    format.print_result(&PartResult::unsolved(solver.year, solver.day, 1));
+                    format.print_result(&PartResult::unsolved(solver.year, solver.day, 2));
+                }
+                continue;
             }
-        })
-        .sum();
+        };
+
+        let params = match Params::load("inputs", solver.year, solver.day) {
+            Ok(params) => params,
+            Err(e) => {
+                eprintln!("Failed to load parameters: {}", e);
+                failed = true;
+                continue;
+            }
+        };
+        let results = match bench {
+            Some(config) => solver.bench(&input, &params, config).map(|report| {
+                format.print_bench(&report);
+                total += report.total();
+                report.results()
+            }),
+            None => solver.solve(&input, &params).inspect(|results| {
+                for result in results {
+                    format.print_result(result);
+                    if result.answer.is_some() {
+                        total += result.elapsed;
+                    }
+                }
+            }),
+        };
+        match results {
+            Ok(results) => recorded.extend(results),
+            Err(e) => {
+                eprintln!("Failed to solve {}", e);
+                failed = true;
+            }
+        }
+    }
+
+    if let Err(e) = history::append(&recorded, bench.is_some()) {
+        eprintln!("Failed to save timings to {}: {}", history::HISTORY_PATH, e);
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    if pretty {
+        println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+    }
+    if failed {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,301 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::str::FromStr;
+
+use crate::bench::{BenchReport, Stats};
+use crate::{PartResult, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+pub const CSV_HEADER: &str = "year,day,part,answer,elapsed_ns,status";
+pub const BENCH_CSV_HEADER: &str = "year,day,phase,answer,runs,min_ns,median_ns,mean_ns,stddev_ns";
+
+/// How `cargo solve` and `cargo all` report results.
+/// `Json` prints one object per line, `Csv` prints a header followed by one row per part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Pretty,
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pretty" => Ok(OutputFormat::Pretty),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!(
+                "unknown output format \"{}\", expected one of: pretty, json, csv",
+                s
+            )),
+        }
+    }
+}
+
+impl OutputFormat {
+    /// Reads the `--format` flag, defaulting to `Pretty`.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(args.opt_value_from_str("--format")?.unwrap_or_default())
+    }
+
+    pub fn print_header(self) {
+        if self == OutputFormat::Csv {
+            println!("{}", CSV_HEADER);
+        }
+    }
+
+    pub fn print_result(self, result: &PartResult) {
+        match self {
+            OutputFormat::Pretty => print_pretty(result),
+            OutputFormat::Json => println!("{}", to_json(result)),
+            OutputFormat::Csv => println!("{}", to_csv(result)),
+        }
+    }
+
+    pub fn print_bench_header(self) {
+        if self == OutputFormat::Csv {
+            println!("{}", BENCH_CSV_HEADER);
+        }
+    }
+
+    /// Prints one row per phase: `parse`, `part_one` and `part_two`.
+    pub fn print_bench(self, report: &BenchReport) {
+        let phases = [
+            ("parse", None, &report.parse),
+            (
+                "part_one",
+                report.parts[0].answer.as_deref(),
+                &report.parts[0].stats,
+            ),
+            (
+                "part_two",
+                report.parts[1].answer.as_deref(),
+                &report.parts[1].stats,
+            ),
+        ];
+        for (phase, answer, stats) in phases {
+            match self {
+                OutputFormat::Pretty => print_bench_pretty(phase, answer, stats),
+                OutputFormat::Json => {
+                    println!(
+                        "{}",
+                        bench_to_json(report.year, report.day, phase, answer, stats)
+                    )
+                }
+                OutputFormat::Csv => {
+                    println!(
+                        "{}",
+                        bench_to_csv(report.year, report.day, phase, answer, stats)
+                    )
+                }
+            }
+        }
+    }
+}
+
+fn print_bench_pretty(phase: &str, answer: Option<&str>, stats: &Stats) {
+    let title = match phase {
+        "part_one" => "Part 1",
+        "part_two" => "Part 2",
+        _ => "Parse",
+    };
+    println!("🎄 {}{}{} 🎄", ANSI_BOLD, title, ANSI_RESET);
+    if phase != "parse" {
+        println!("{}", answer.unwrap_or("not solved."));
+    }
+    println!(
+        "{}median: {:.2?}, min: {:.2?}, mean: {:.2?}, stddev: {:.2?} ({} runs){}",
+        ANSI_ITALIC, stats.median, stats.min, stats.mean, stats.stddev, stats.runs, ANSI_RESET
+    );
+}
+
+fn print_pretty(result: &PartResult) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+    match &result.answer {
+        Some(answer) => {
+            println!(
+                "{} {}(elapsed: {:.2?}){}",
+                answer, ANSI_ITALIC, result.elapsed, ANSI_RESET
+            );
+        }
+        None => {
+            println!("not solved.")
+        }
+    }
+}
+
+fn status(result: &PartResult) -> &'static str {
+    match result.answer {
+        Some(_) => "solved",
+        None => "unsolved",
+    }
+}
+
+fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn escape_csv(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+pub fn to_json(result: &PartResult) -> String {
+    let answer = match &result.answer {
+        Some(answer) => format!("\"{}\"", escape_json(answer)),
+        None => "null".to_string(),
+    };
+    format!(
+        "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ns\":{},\"status\":\"{}\"}}",
+        result.year,
+        result.day,
+        result.part,
+        answer,
+        result.elapsed.as_nanos(),
+        status(result)
+    )
+}
+
+pub fn to_csv(result: &PartResult) -> String {
+    format!(
+        "{},{},{},{},{},{}",
+        result.year,
+        result.day,
+        result.part,
+        escape_csv(result.answer.as_deref().unwrap_or_default()),
+        result.elapsed.as_nanos(),
+        status(result)
+    )
+}
+
+pub fn bench_to_json(
+    year: i16,
+    day: u8,
+    phase: &str,
+    answer: Option<&str>,
+    stats: &Stats,
+) -> String {
+    let answer = match answer {
+        Some(answer) => format!("\"{}\"", escape_json(answer)),
+        None => "null".to_string(),
+    };
+    format!(
+        "{{\"year\":{},\"day\":{},\"phase\":\"{}\",\"answer\":{},\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+        year,
+        day,
+        phase,
+        answer,
+        stats.runs,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.stddev.as_nanos()
+    )
+}
+
+pub fn bench_to_csv(
+    year: i16,
+    day: u8,
+    phase: &str,
+    answer: Option<&str>,
+    stats: &Stats,
+) -> String {
+    format!(
+        "{},{},{},{},{},{},{},{},{}",
+        year,
+        day,
+        phase,
+        escape_csv(answer.unwrap_or_default()),
+        stats.runs,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.stddev.as_nanos()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn result(answer: Option<&str>, elapsed: Duration) -> PartResult {
+        PartResult {
+            year: 2022,
+            day: 5,
+            part: 2,
+            answer: answer.map(String::from),
+            elapsed,
+        }
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            to_json(&result(Some("MCD"), Duration::from_nanos(74))),
+            r#"{"year":2022,"day":5,"part":2,"answer":"MCD","elapsed_ns":74,"status":"solved"}"#
+        );
+        assert_eq!(
+            to_json(&result(None, Duration::ZERO)),
+            r#"{"year":2022,"day":5,"part":2,"answer":null,"elapsed_ns":0,"status":"unsolved"}"#
+        );
+        assert_eq!(
+            to_json(&result(Some("a\"b\nc"), Duration::from_micros(1))),
+            r#"{"year":2022,"day":5,"part":2,"answer":"a\"b\nc","elapsed_ns":1000,"status":"solved"}"#
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&result(Some("1514285714288"), Duration::from_millis(3))),
+            "2022,5,2,1514285714288,3000000,solved"
+        );
+        assert_eq!(
+            to_csv(&result(None, Duration::ZERO)),
+            "2022,5,2,,0,unsolved"
+        );
+        assert_eq!(
+            to_csv(&result(Some("a,\"b\""), Duration::from_nanos(1))),
+            "2022,5,2,\"a,\"\"b\"\"\",1,solved"
+        );
+    }
+
+    #[test]
+    fn test_bench_rows() {
+        let stats = Stats {
+            runs: 10,
+            min: Duration::from_nanos(90),
+            median: Duration::from_nanos(100),
+            mean: Duration::from_nanos(105),
+            stddev: Duration::from_nanos(7),
+        };
+        assert_eq!(
+            bench_to_json(2022, 5, "part_one", Some("CMZ"), &stats),
+            r#"{"year":2022,"day":5,"phase":"part_one","answer":"CMZ","runs":10,"min_ns":90,"median_ns":100,"mean_ns":105,"stddev_ns":7}"#
+        );
+        assert_eq!(
+            bench_to_csv(2022, 5, "parse", None, &stats),
+            "2022,5,parse,,10,90,100,105,7"
+        );
+    }
+}
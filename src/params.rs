@@ -0,0 +1,141 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::str::FromStr;
+
+use crate::answers::parse_value;
+use crate::error::{parse_lines, SolveError};
+use crate::{file_path, invalid_arg};
+
+/// Values a puzzle gives in its text rather than in its input, such as the row to look at in
+/// day 15 of 2022. The examples often use smaller ones than the real input does.
+///
+/// Solutions read them in `Solution::parse_with`, and fall back to the values for the real input.
+/// Overrides are stored next to an input in `src/<folder>/<year>/NN.toml`, using the same
+/// `key = value` format as the answers, and can be passed with `--param key=value`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    /// Loads the parameters stored for an input. A missing file means there are none.
+    pub fn load(folder: &str, year: i16, day: u8) -> Result<Self, SolveError> {
+        let path = file_path(folder, year, day, "toml").map_err(|e| e.to_string())?;
+        match fs::read_to_string(&path) {
+            Ok(contents) => Self::parse(&contents)
+                .map_err(|e| SolveError::new(format!("{}: {}", path.display(), e))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e).into()),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, SolveError> {
+        let mut params = Self::default();
+        let entries = parse_lines(contents, |line| {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                return Ok(None);
+            }
+            let (key, value) = line.split_once('=').ok_or("expected `key = value`")?;
+            Ok(Some((key.trim().to_string(), parse_value(value.trim())?)))
+        })?;
+        for (key, value) in entries.into_iter().flatten() {
+            params.set(&key, &value);
+        }
+        Ok(params)
+    }
+
+    /// Reads every `--param key=value`.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let mut params = Self::default();
+        for arg in args.values_from_str::<_, String>("--param")? {
+            match arg.split_once('=') {
+                Some((key, value)) => params.set(key.trim(), value.trim()),
+                None => return Err(invalid_arg(arg, "--param needs a `key=value` pair")),
+            }
+        }
+        Ok(params)
+    }
+
+    /// Sets every value in `overrides`, replacing the ones already here.
+    pub fn merge(&mut self, overrides: &Params) {
+        self.values.extend(overrides.values.clone());
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) {
+        self.values.insert(key.to_string(), value.to_string());
+    }
+
+    /// The value of `key`, or `default` if it was not given.
+    pub fn get<T>(&self, key: &str, default: T) -> Result<T, SolveError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        match self.values.get(key) {
+            Some(value) => value
+                .parse()
+                .map_err(|e| format!("invalid parameter {} = \"{}\": {}", key, value, e).into()),
+            None => Ok(default),
+        }
+    }
+
+    /// The comma separated list of values of `key`, or `default` if it was not given.
+    pub fn get_list<T>(&self, key: &str, default: &[T]) -> Result<Vec<T>, SolveError>
+    where
+        T: FromStr + Clone,
+        T::Err: fmt::Display,
+    {
+        match self.values.get(key) {
+            Some(values) => values
+                .split(",")
+                .map(|value| {
+                    value.trim().parse().map_err(|e| {
+                        format!("invalid parameter {} = \"{}\": {}", key, values, e).into()
+                    })
+                })
+                .collect(),
+            None => Ok(default.to_vec()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let params = Params::parse("# example\nrow = 10\ncycles = \"1, 2\"\n").unwrap();
+        assert_eq!(params.get("row", 2000000), Ok(10));
+        assert_eq!(params.get("max", 4000000), Ok(4000000));
+        assert_eq!(params.get_list("cycles", &[20]), Ok(vec![1, 2]));
+        assert!(params.get::<u8>("cycles", 0).is_err());
+
+        let e = Params::parse("row = 10\nmax\n").unwrap_err();
+        assert_eq!(e.line, Some(2));
+    }
+
+    #[test]
+    fn test_from_args() {
+        let args = ["--param", "row=20", "--example", "--param", "max = 20"];
+        let mut args = pico_args::Arguments::from_vec(args.map(Into::into).to_vec());
+        let overrides = Params::from_args(&mut args).unwrap();
+        assert_eq!(args.finish(), ["--example"]);
+
+        let mut params = Params::parse("row = 10\ncycles = 5\n").unwrap();
+        params.merge(&overrides);
+        assert_eq!(params.get("row", 0), Ok(20));
+        assert_eq!(params.get("max", 0), Ok(20));
+        assert_eq!(params.get("cycles", 0), Ok(5));
+
+        let mut args = pico_args::Arguments::from_vec(vec!["--param".into(), "row".into()]);
+        assert!(Params::from_args(&mut args).is_err());
+    }
+}
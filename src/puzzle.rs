@@ -0,0 +1,197 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use regex::Regex;
+
+/// The puzzle description articles, in order. Part two only appears once part one is solved.
+fn articles(html: &str) -> Vec<&str> {
+    let re = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    re.captures_iter(html)
+        .map(|captures| captures.get(1).unwrap().as_str())
+        .collect()
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn strip_tags(html: &str) -> String {
+    let re = Regex::new(r"<[^>]*>").unwrap();
+    decode_entities(&re.replace_all(html, ""))
+}
+
+/// The contents of the first `<pre><code>` block, which is the example input on most days.
+pub fn example(html: &str) -> Option<String> {
+    let re = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let example = strip_tags(re.captures(html)?.get(1)?.as_str());
+    Some(example.trim_end_matches('\n').to_string() + "\n")
+}
+
+/// The expected example answer of each part, if it can be found.
+/// The puzzle text highlights it as `<code><em>answer</em></code>`, usually as the last such
+/// highlight in the article of that part.
+pub fn expected_answers(html: &str) -> [Option<String>; 2] {
+    let re = Regex::new(r"<code><em>([^<]*)</em></code>").unwrap();
+    let articles = articles(html);
+    let answer = |part: usize| {
+        let article = articles.get(part)?;
+        let captures = re.captures_iter(article).last()?;
+        Some(decode_entities(captures.get(1)?.as_str()))
+    };
+    [answer(0), answer(1)]
+}
+
+/// Converts the puzzle articles of a day page to Markdown.
+pub fn to_markdown(html: &str, url: &str) -> String {
+    let mut markdown: String = articles(html)
+        .iter()
+        .map(|article| article_to_markdown(article))
+        .collect::<Vec<String>>()
+        .join("\n");
+    markdown.push_str(&format!("\nSource: <{}>\n", url));
+    markdown
+}
+
+fn article_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let (mut in_pre, mut in_code) = (false, false);
+    let mut links: Vec<String> = vec![];
+    let href_re = Regex::new(r#"href="([^"]*)""#).unwrap();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        let text = &rest[..start];
+        // newlines between block tags would otherwise pile up as empty lines.
+        if in_pre || !text.trim().is_empty() || !text.contains('\n') {
+            out.push_str(&decode_entities(text));
+        }
+        let Some(end) = rest[start..].find('>').map(|end| start + end) else {
+            break;
+        };
+        let tag = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match (name.as_str(), closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2", true) | ("p", true) => out.push_str("\n\n"),
+            ("ul", true) => out.push('\n'),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => {
+                in_code = !closing;
+                out.push('`');
+            }
+            ("em", _) if !in_pre && !in_code => out.push_str("**"),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("a", false) => {
+                let href = href_re
+                    .captures(tag)
+                    .map(|captures| captures[1].to_string())
+                    .unwrap_or_default();
+                links.push(href);
+                out.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({})", href));
+            }
+            _ => {}
+        }
+    }
+    out.push_str(&decode_entities(rest));
+    out.trim_end().to_string() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <em>Calories</em>, see <a href="/2022/about">here</a>.</p>
+<p>For example:</p>
+<pre><code>1000
+2000
+
+<em>3000</em>
+</code></pre>
+<ul><li>The first Elf is carrying <code>1000</code> &amp; <code>2000</code>.</li></ul>
+<p>In the example above, this is <em><code>3000</code></em>: the Elf carries <code><em>24000</em></code> Calories.</p>
+</article>
+<p>Your puzzle answer was <code>69</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>The top three Elves carry <code><em>45000</em></code> Calories.</p></article>
+</main>"#;
+
+    #[test]
+    fn test_example() {
+        assert_eq!(example(PAGE), Some("1000\n2000\n\n3000\n".to_string()));
+        assert_eq!(example("<p>no example</p>"), None);
+    }
+
+    #[test]
+    fn test_expected_answers() {
+        assert_eq!(
+            expected_answers(PAGE),
+            [Some("24000".to_string()), Some("45000".to_string())]
+        );
+        let part_one_only = &PAGE[..PAGE.find("<p>Your puzzle").unwrap()];
+        assert_eq!(
+            expected_answers(part_one_only),
+            [Some("24000".to_string()), None]
+        );
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let markdown = to_markdown(PAGE, "https://adventofcode.com/2022/day/1");
+        assert_eq!(
+            markdown,
+            "## --- Day 1: Calorie Counting ---
+
+The Elves take turns writing down the number of **Calories**, see [here](/2022/about).
+
+For example:
+
+```
+1000
+2000
+
+3000
+```
+
+- The first Elf is carrying `1000` & `2000`.
+
+In the example above, this is **`3000`**: the Elf carries `24000` Calories.
+
+## --- Part Two ---
+
+The top three Elves carry `45000` Calories.
+
+Source: <https://adventofcode.com/2022/day/1>
+"
+        );
+    }
+}
@@ -0,0 +1,315 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Every submitted answer and the site's verdict, one tab-separated line each.
+pub const LOG_PATH: &str = "submissions.log";
+
+/// How the site responded to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// An answer was submitted too recently, `wait` is the remaining cooldown if it could be read.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part has already been completed, so the site did not check the answer.
+    AlreadySolved,
+    Unknown,
+}
+
+impl Outcome {
+    /// Classifies the HTML returned by the answer endpoint.
+    pub fn parse(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Outcome::Correct
+        } else if response.contains("You gave an answer too recently") {
+            Outcome::RateLimited {
+                wait: parse_wait(response),
+            }
+        } else if response.contains("Did you already complete it?") {
+            Outcome::AlreadySolved
+        } else if response.contains("That's not the right answer") {
+            if response.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if response.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    /// Whether the answer was checked and turned out to be wrong.
+    pub fn is_rejection(self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Wrong => "wrong",
+            Outcome::RateLimited { .. } => "rate_limited",
+            Outcome::AlreadySolved => "already_solved",
+            Outcome::Unknown => "unknown",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        Some(match key {
+            "correct" => Outcome::Correct,
+            "too_high" => Outcome::TooHigh,
+            "too_low" => Outcome::TooLow,
+            "wrong" => Outcome::Wrong,
+            "rate_limited" => Outcome::RateLimited { wait: None },
+            "already_solved" => Outcome::AlreadySolved,
+            "unknown" => Outcome::Unknown,
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "⭐ That's the right answer!"),
+            Outcome::TooHigh => write!(f, "❌ That's not the right answer, it is too high."),
+            Outcome::TooLow => write!(f, "❌ That's not the right answer, it is too low."),
+            Outcome::Wrong => write!(f, "❌ That's not the right answer."),
+            Outcome::RateLimited { wait: Some(wait) } => {
+                write!(
+                    f,
+                    "⏳ Answered too recently, wait {:?} before trying again.",
+                    wait
+                )
+            }
+            Outcome::RateLimited { wait: None } => {
+                write!(
+                    f,
+                    "⏳ Answered too recently, wait a bit before trying again."
+                )
+            }
+            Outcome::AlreadySolved => write!(f, "This part has already been solved."),
+            Outcome::Unknown => write!(f, "Could not understand the response."),
+        }
+    }
+}
+
+/// Reads the cooldown from "You have 1m 5s left to wait."
+fn parse_wait(response: &str) -> Option<Duration> {
+    let (_, rest) = response.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.len().checked_sub(1)?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// One line of the submission log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub timestamp: u64,
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+impl Submission {
+    pub fn new(year: i16, day: u8, part: u8, answer: &str, outcome: Outcome) -> Self {
+        Submission {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            year,
+            day,
+            part,
+            outcome,
+            answer: answer.to_string(),
+        }
+    }
+
+    /// The answer goes last so that it may contain any character but a newline.
+    pub fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.year,
+            self.day,
+            self.part,
+            self.outcome.key(),
+            self.answer
+        )
+    }
+
+    pub fn from_line(line: &str) -> Option<Self> {
+        let fields: Vec<&str> = line.splitn(6, "\t").collect();
+        if fields.len() != 6 {
+            return None;
+        }
+        Some(Submission {
+            timestamp: fields[0].parse().ok()?,
+            year: fields[1].parse().ok()?,
+            day: fields[2].parse().ok()?,
+            part: fields[3].parse().ok()?,
+            outcome: Outcome::from_key(fields[4])?,
+            answer: fields[5].to_string(),
+        })
+    }
+
+    fn is_for(&self, year: i16, day: u8, part: u8) -> bool {
+        self.year == year && self.day == day && self.part == part
+    }
+}
+
+pub fn load_log() -> Result<Vec<Submission>, String> {
+    let contents = match fs::read_to_string(LOG_PATH) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(format!("{}: {}", LOG_PATH, e)),
+    };
+    contents
+        .split("\n")
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            Submission::from_line(line).ok_or(format!("{}:{}: malformed line", LOG_PATH, i + 1))
+        })
+        .collect()
+}
+
+pub fn append_log(submission: &Submission) -> io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(LOG_PATH)?;
+    writeln!(file, "{}", submission.to_line())
+}
+
+/// Refuses answers the log already proves wrong: repeated rejections, numbers outside the
+/// range narrowed down by earlier "too high"/"too low" responses, and parts that are already solved.
+pub fn check_guess(
+    log: &[Submission],
+    year: i16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<(), String> {
+    let previous = log.iter().filter(|s| s.is_for(year, day, part));
+    let number = answer.parse::<i64>().ok();
+    for submission in previous {
+        match submission.outcome {
+            Outcome::Correct => {
+                return Err(format!(
+                    "part {} was already solved with {}",
+                    part, submission.answer
+                ))
+            }
+            outcome if outcome.is_rejection() && submission.answer == answer => {
+                return Err(format!("{} was already rejected", answer))
+            }
+            _ => {}
+        }
+        let (Some(number), Ok(bound)) = (number, submission.answer.parse::<i64>()) else {
+            continue;
+        };
+        match submission.outcome {
+            Outcome::TooHigh if number >= bound => {
+                return Err(format!("{} is too high, {} already was", answer, bound))
+            }
+            Outcome::TooLow if number <= bound => {
+                return Err(format!("{} is too low, {} already was", answer, bound))
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_outcome() {
+        assert_eq!(
+            Outcome::parse("<article><p>That's the right answer! You are one gold star closer"),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse("<p>That's not the right answer; your answer is too high."),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::parse("<p>That's not the right answer; your answer is too low."),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse("<p>That's not the right answer. If you're stuck"),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            Outcome::parse("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."),
+            Outcome::RateLimited {
+                wait: Some(Duration::from_secs(65))
+            }
+        );
+        assert_eq!(
+            Outcome::parse(
+                "<p>You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Outcome::AlreadySolved
+        );
+        assert_eq!(Outcome::parse("<html></html>"), Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_log_line_round_trip() {
+        let submission = Submission::new(2022, 5, 1, "CMZ", Outcome::Wrong);
+        assert_eq!(
+            Submission::from_line(&submission.to_line()),
+            Some(submission)
+        );
+        assert_eq!(Submission::from_line("1\t2022\t5"), None);
+    }
+
+    #[test]
+    fn test_check_guess() {
+        let log = vec![
+            Submission::new(2022, 1, 1, "100", Outcome::TooHigh),
+            Submission::new(2022, 1, 1, "10", Outcome::TooLow),
+            Submission::new(2022, 1, 1, "42", Outcome::Wrong),
+            Submission::new(2022, 1, 2, "7", Outcome::Correct),
+        ];
+        assert!(check_guess(&log, 2022, 1, 1, "50").is_ok());
+        assert!(check_guess(&log, 2022, 1, 1, "42").is_err());
+        assert!(check_guess(&log, 2022, 1, 1, "100").is_err());
+        assert!(check_guess(&log, 2022, 1, 1, "150").is_err());
+        assert!(check_guess(&log, 2022, 1, 1, "5").is_err());
+        assert!(check_guess(&log, 2022, 1, 2, "8").is_err());
+        assert!(check_guess(&log, 2021, 1, 1, "150").is_ok());
+    }
+}
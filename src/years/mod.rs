@@ -0,0 +1,42 @@
+/*
+ * Every year is registered here, and every year registers its days in its own `mod.rs`.
+ * `cargo scaffold` adds new years and days to these lists.
+ */
+use crate::Solver;
+
+macro_rules! register {
+    ($($module:ident => $solution:ident,)*) => {
+        $(pub mod $module;)*
+
+        /// All registered solutions of this year, ordered by day.
+        pub fn all() -> Vec<crate::Solver> {
+            let mut solvers = vec![$(crate::Solver::new::<$module::$solution>(),)*];
+            solvers.sort_by_key(|solver| solver.day);
+            solvers
+        }
+    };
+}
+
+macro_rules! years {
+    ($($module:ident,)*) => {
+        $(pub mod $module;)*
+
+        /// All registered solutions, ordered by year and day.
+        pub fn all() -> Vec<Solver> {
+            let mut solvers: Vec<Solver> = vec![$($module::all(),)*].concat();
+            solvers.sort_by_key(|solver| (solver.year, solver.day));
+            solvers
+        }
+    };
+}
+
+years! {
+    y2022,
+}
+
+/// The registered solution of one day, if there is one.
+pub fn find(year: i16, day: u8) -> Option<Solver> {
+    all()
+        .into_iter()
+        .find(|solver| solver.year == year && solver.day == day)
+}
@@ -0,0 +1,30 @@
+use crate::error::{parse_blocks, parse_lines, SolveError};
+use crate::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 1;
+
+    type Input = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_blocks(input, |group| {
+            let foods = parse_lines(group, |food| Ok(food.trim().parse::<u32>()?))?;
+            Ok(foods.iter().sum())
+        })
+    }
+
+    fn part_one(totals: &Self::Input) -> Option<u32> {
+        totals.iter().max().cloned()
+    }
+
+    fn part_two(totals: &Self::Input) -> Option<u32> {
+        let mut totals = totals.clone();
+        totals.sort();
+        Some(totals.iter().rev().take(3).sum())
+    }
+}
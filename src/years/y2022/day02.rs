@@ -0,0 +1,104 @@
+use crate::error::{parse_lines, SolveError};
+use crate::Solution;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 2;
+
+    type Input = Vec<(char, char)>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input, |s| {
+            let chars: Vec<char> = s.chars().collect();
+            match chars[..] {
+                [play @ 'A'..='C', ' ', response @ 'X'..='Z'] => Ok((play, response)),
+                _ => Err(format!("expected a play and a response, got \"{}\"", s).into()),
+            }
+        })
+    }
+
+    fn part_one(games: &Self::Input) -> Option<u32> {
+        let mut result = 0;
+        for game in games {
+            result += match game.0 {
+                'A' => match game.1 {
+                    'X' => 1 + 3,
+                    'Y' => 2 + 6,
+                    'Z' => 3,
+                    _ => panic!("Unexpected response {}", game.1),
+                },
+                'B' => match game.1 {
+                    'X' => 1,
+                    'Y' => 2 + 3,
+                    'Z' => 3 + 6,
+                    _ => panic!("Unexpected response {}", game.1),
+                },
+                'C' => match game.1 {
+                    'X' => 1 + 6,
+                    'Y' => 2,
+                    'Z' => 3 + 3,
+                    _ => panic!("Unexpected response {}", game.1),
+                },
+                _ => panic!("Unexpected play {}", game.1),
+            }
+        }
+        Some(result)
+    }
+
+    fn part_two(games: &Self::Input) -> Option<u32> {
+        let mut result = 0;
+        for game in games {
+            result += match game.0 {
+                'A' => match game.1 {
+                    'X' => 3,
+                    'Y' => 1 + 3,
+                    'Z' => 2 + 6,
+                    _ => panic!("Unexpected response {}", game.1),
+                },
+                'B' => match game.1 {
+                    'X' => 1,
+                    'Y' => 2 + 3,
+                    'Z' => 3 + 6,
+                    _ => panic!("Unexpected response {}", game.1),
+                },
+                'C' => match game.1 {
+                    'X' => 2,
+                    'Y' => 3 + 3,
+                    'Z' => 1 + 6,
+                    _ => panic!("Unexpected response {}", game.1),
+                },
+                _ => panic!("Unexpected play {}", game.1),
+            }
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day02::parse(&crate::read_file("examples", 2022, 2)).unwrap();
+        assert_eq!(Day02::part_one(&input), Some(15));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day02::parse(&crate::read_file("examples", 2022, 2)).unwrap();
+        assert_eq!(Day02::part_two(&input), Some(12));
+    }
+
+    #[test]
+    fn test_malformed() {
+        for line in ["AXY", "A  Y", "A Y Z", "A-Y"] {
+            let e = Day02::parse(&format!("A Y\n{}\nC Z", line)).unwrap_err();
+            assert_eq!(e.line, Some(2));
+        }
+    }
+}
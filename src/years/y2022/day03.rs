@@ -0,0 +1,72 @@
+use crate::error::SolveError;
+use crate::Solution;
+
+fn score(c: char) -> u32 {
+    match c.is_ascii_lowercase() {
+        true => (c as u32 - 'a' as u32) + 1,
+        false => (c as u32 - 'A' as u32) + 27,
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.trim().split("\n").map(String::from).collect())
+    }
+
+    fn part_one(sacks: &Self::Input) -> Option<u32> {
+        let mut result = 0;
+        for (left, right) in sacks.iter().map(|s| s.split_at(s.len() / 2)) {
+            for c in left.chars() {
+                if right.contains(c) {
+                    result += score(c);
+                    break;
+                }
+            }
+        }
+        Some(result)
+    }
+
+    fn part_two(sacks: &Self::Input) -> Option<u32> {
+        let mut result = 0;
+        for group in sacks.chunks(3) {
+            let (first, second, third) = (&group[0], &group[1], &group[2]);
+            // Theoretically we should collect second and third into HashSet<char> or
+            // something to avoid the O(n^2) lookups, but the input lines are short enough
+            // that this actually makes the loop far slower, even though it improves the
+            // time complexity.
+            for c in first.chars() {
+                if second.contains(c) && third.contains(c) {
+                    result += score(c);
+                    break;
+                }
+            }
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day03::parse(&crate::read_file("examples", 2022, 3)).unwrap();
+        assert_eq!(Day03::part_one(&input), Some(157));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day03::parse(&crate::read_file("examples", 2022, 3)).unwrap();
+        assert_eq!(Day03::part_two(&input), Some(70));
+    }
+}
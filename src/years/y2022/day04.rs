@@ -0,0 +1,74 @@
+use crate::error::{parse_lines, SolveError};
+use crate::Solution;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl Range {
+    fn contains(self, other: Range) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+    fn overlaps(self, other: Range) -> bool {
+        (self.start >= other.start && self.start <= other.end)
+            || (self.end >= other.start && self.end <= other.end)
+            || (other.start >= self.start && other.start <= self.end)
+            || (other.end >= self.start && other.end <= self.end)
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 4;
+
+    type Input = Vec<(Range, Range)>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input, |s| {
+            let digits = s
+                .split(",")
+                .flat_map(|r| r.split("-"))
+                .map(|b| b.parse::<u32>())
+                .collect::<Result<Vec<u32>, _>>()?;
+            match digits[..] {
+                [first_start, first_end, second_start, second_end] => Ok((
+                    Range {
+                        start: first_start,
+                        end: first_end,
+                    },
+                    Range {
+                        start: second_start,
+                        end: second_end,
+                    },
+                )),
+                _ => Err(format!("expected two ranges, got \"{}\"", s).into()),
+            }
+        })
+    }
+
+    fn part_one(sections: &Self::Input) -> Option<u32> {
+        let mut result = 0;
+        for section in sections {
+            if section.0.contains(section.1) || section.1.contains(section.0) {
+                result += 1;
+            }
+        }
+        Some(result)
+    }
+
+    fn part_two(sections: &Self::Input) -> Option<u32> {
+        let mut result = 0;
+        for section in sections {
+            if section.0.overlaps(section.1) {
+                result += 1;
+            }
+        }
+        Some(result)
+    }
+}
@@ -0,0 +1,90 @@
+use crate::error::{parse_lines, SolveError};
+use crate::helpers::parse::{columns, scan};
+use crate::Solution;
+
+#[derive(Debug, Default)]
+pub struct Crates {
+    positions: Vec<Vec<char>>,
+    moves: Vec<(usize, usize, usize)>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 5;
+
+    type Input = Crates;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let (pos, mvs) = input
+            .split_once("\n\n")
+            .ok_or("expected the crates and the moves to be separated by an empty line")?;
+        let mut positions = Vec::new();
+        for line in pos.split("\n").collect::<Vec<&str>>().iter().rev().skip(1) {
+            for (i, crat) in columns(line, 4).into_iter().enumerate() {
+                if positions.len() <= i {
+                    positions.push(vec![]);
+                }
+                match crat.chars().nth(1) {
+                    Some(' ') | None => {}
+                    Some(c) => positions[i].push(c),
+                }
+            }
+        }
+        let stacks = 1..=positions.len();
+        let moves = parse_lines(mvs, |line| {
+            let mv: (usize, usize, usize) = scan("move {} from {} to {}", line)?;
+            if !stacks.contains(&mv.1) || !stacks.contains(&mv.2) {
+                return Err(format!("there is no stack to move between in \"{}\"", line).into());
+            }
+            Ok(mv)
+        })
+        .map_err(|e| e.offset(pos.split("\n").count() + 2))?;
+        Ok(Crates { positions, moves })
+    }
+
+    fn part_one(crates: &Self::Input) -> Option<String> {
+        let mut positions = crates.positions.clone();
+        for mv in &crates.moves {
+            let (from, to) = (mv.1 - 1, mv.2 - 1);
+            for _ in 0..mv.0 {
+                let c = positions[from].pop().unwrap();
+                positions[to].push(c);
+            }
+        }
+        let result: String = positions.iter().map(|p| *p.last().unwrap()).collect();
+        Some(result)
+    }
+
+    fn part_two(crates: &Self::Input) -> Option<String> {
+        let mut positions = crates.positions.clone();
+        for mv in &crates.moves {
+            let (from, to) = (mv.1 - 1, mv.2 - 1);
+            let depth = positions[from].len() - mv.0;
+            let movers = positions[from].split_off(depth);
+            positions[to].extend(movers);
+        }
+        let result: String = positions.iter().map(|p| *p.last().unwrap()).collect();
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day05::parse(&crate::read_file("examples", 2022, 5)).unwrap();
+        assert_eq!(Day05::part_one(&input), Some("CMZ".to_string()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day05::parse(&crate::read_file("examples", 2022, 5)).unwrap();
+        assert_eq!(Day05::part_two(&input), Some("MCD".to_string()));
+    }
+}
@@ -0,0 +1,38 @@
+use crate::error::SolveError;
+use crate::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 6;
+
+    type Input = Vec<u8>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.trim().bytes().collect())
+    }
+
+    fn part_one(chars: &Self::Input) -> Option<usize> {
+        for (i, window) in chars.windows(4).enumerate() {
+            if !(window[1..].contains(&window[0])
+                || window[2..].contains(&window[1])
+                || window[3..].contains(&window[2]))
+            {
+                return Some(i + 4);
+            }
+        }
+        None
+    }
+
+    fn part_two(chars: &Self::Input) -> Option<usize> {
+        for (i, window) in chars.windows(14).enumerate() {
+            if !(0..13).any(|j| window[(j + 1)..].contains(&window[j])) {
+                return Some(i + 14);
+            }
+        }
+        None
+    }
+}
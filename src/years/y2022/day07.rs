@@ -0,0 +1,140 @@
+use std::str::FromStr;
+
+use crate::error::{parse_lines, SolveError};
+use crate::Solution;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    Cd(String),
+    CdUp,
+    Ls,
+    Dir(String),
+    File(usize),
+}
+
+impl FromStr for Line {
+    type Err = SolveError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(" ").collect();
+        match parts[..] {
+            ["$", "cd", ".."] => Ok(Line::CdUp),
+            ["$", "cd", dir] => Ok(Line::Cd(dir.to_string())),
+            ["$", "ls"] => Ok(Line::Ls),
+            ["dir", dir] => Ok(Line::Dir(dir.to_string())),
+            [size, _] => Ok(Line::File(size.parse()?)),
+            _ => Err(format!("unknown line \"{}\"", s).into()),
+        }
+    }
+}
+
+fn p1(lines: &[Line], mut i: usize, acc: &mut usize) -> (usize, usize) {
+    let mut size = 0;
+    let len = lines.len();
+    while i < len {
+        match lines[i] {
+            Line::Ls | Line::Dir(_) => {}
+            Line::CdUp => return (i, size),
+            Line::Cd(_) => {
+                let (new_i, dsize) = p1(lines, i + 1, acc);
+                i = new_i;
+                if dsize <= 100000 {
+                    *acc += dsize;
+                }
+                size += dsize;
+            }
+            Line::File(file_size) => size += file_size,
+        }
+        i += 1;
+    }
+    (i, size)
+}
+
+fn p2(lines: &[Line], mut i: usize, best: &mut usize, target: usize) -> (usize, usize) {
+    let mut size = 0;
+    let len = lines.len();
+    while i < len {
+        match lines[i] {
+            Line::Ls | Line::Dir(_) => {}
+            Line::CdUp => return (i, size),
+            Line::Cd(_) => {
+                let (new_i, dsize) = p2(lines, i + 1, best, target);
+                i = new_i;
+                if dsize >= target && dsize < *best {
+                    *best = dsize;
+                }
+                size += dsize;
+            }
+            Line::File(file_size) => size += file_size,
+        }
+        i += 1;
+    }
+    (i, size)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 7;
+
+    type Input = Vec<Line>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let lines = parse_lines(input, |line| match line.trim() {
+            "" => Ok(None),
+            line => line.parse().map(Some),
+        })?;
+        Ok(lines.into_iter().flatten().collect())
+    }
+
+    fn part_one(lines: &Self::Input) -> Option<usize> {
+        let mut acc = 0;
+        p1(lines, 0, &mut acc);
+        Some(acc)
+    }
+
+    fn part_two(lines: &Self::Input) -> Option<usize> {
+        let total_size: usize = lines
+            .iter()
+            .map(|line| match line {
+                Line::File(size) => *size,
+                _ => 0,
+            })
+            .sum();
+        // If less than 40000000 is used there is already enough space, and any directory will do
+        let target = total_size.saturating_sub(40000000);
+        let mut best = usize::MAX;
+        p2(lines, 0, &mut best, target);
+        Some(best)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day07::parse(&crate::read_file("examples", 2022, 7)).unwrap();
+        assert_eq!(Day07::part_one(&input), Some(95437));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day07::parse(&crate::read_file("examples", 2022, 7)).unwrap();
+        assert_eq!(Day07::part_two(&input), Some(24933642));
+    }
+
+    #[test]
+    fn test_malformed() {
+        let e = Day07::parse("$ cd /\n$ ls\n$ rm a\n").unwrap_err();
+        assert_eq!(e.line, Some(3));
+        let e = Day07::parse("$ cd /\n$ ls\nbig a.txt\n").unwrap_err();
+        assert_eq!(e.line, Some(3));
+        // Blank lines still count towards the line numbers
+        let e = Day07::parse("\n$ cd /\n\n$ ls\nbig a.txt\n").unwrap_err();
+        assert_eq!(e.line, Some(5));
+    }
+}
@@ -0,0 +1,71 @@
+use crate::error::SolveError;
+use crate::helpers::{Grid, NEIGHBOURS4};
+use crate::Solution;
+
+/// How many trees can be seen from `pos` looking in the direction of `delta`.
+fn viewing_distance(trees: &Grid<u8>, pos: [usize; 2], delta: [isize; 2]) -> u32 {
+    let mut num_visible = 0;
+    for tree in trees.ray(pos, delta) {
+        num_visible += 1;
+        if trees[tree] >= trees[pos] {
+            break;
+        }
+    }
+    num_visible
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 8;
+
+    type Input = Grid<u8>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Grid::parse(input, |c| match c {
+            '0'..='9' => Ok(c as u8 - b'0'),
+            _ => Err(format!("expected a tree height, got '{}'", c).into()),
+        })
+    }
+
+    fn part_one(trees: &Self::Input) -> Option<u32> {
+        let visible = trees.positions().filter(|pos| {
+            NEIGHBOURS4
+                .iter()
+                .any(|delta| trees.ray(*pos, *delta).all(|tree| trees[tree] < trees[*pos]))
+        });
+        Some(visible.count() as u32)
+    }
+
+    fn part_two(trees: &Self::Input) -> Option<u32> {
+        trees
+            .positions()
+            .map(|pos| {
+                NEIGHBOURS4
+                    .iter()
+                    .map(|delta| viewing_distance(trees, pos, *delta))
+                    .product()
+            })
+            .max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day08::parse(&crate::read_file("examples", 2022, 8)).unwrap();
+        assert_eq!(Day08::part_one(&input), Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day08::parse(&crate::read_file("examples", 2022, 8)).unwrap();
+        assert_eq!(Day08::part_two(&input), Some(8));
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use crate::error::{parse_lines, SolveError};
+use crate::helpers::{Direction, Point2};
+use crate::Solution;
+
+/// Drags a rope of `knots` knots along `movements` and counts the positions its tail visits.
+fn tail_positions(movements: &[(Direction, i32)], knots: usize) -> usize {
+    let mut rope = vec![Point2::new(0, 0); knots];
+    let mut visited = HashSet::from([rope[knots - 1]]);
+    for &(direction, distance) in movements {
+        for _ in 0..distance {
+            rope[0] = rope[0].step(direction);
+            for i in 1..knots {
+                let (head, tail) = (rope[i - 1], rope[i]);
+                if head.chebyshev(tail) > 1 {
+                    rope[i] += (head - tail).signum();
+                }
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+    visited.len()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 9;
+
+    type Input = Vec<(Direction, i32)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input, |s| {
+            let (direction, distance) = s
+                .split_once(" ")
+                .ok_or_else(|| format!("expected a direction and a distance, got \"{}\"", s))?;
+            Ok((direction.parse()?, distance.parse()?))
+        })
+    }
+
+    fn part_one(movements: &Self::Input) -> Option<usize> {
+        Some(tail_positions(movements, 2))
+    }
+
+    fn part_two(movements: &Self::Input) -> Option<usize> {
+        Some(tail_positions(movements, 10))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day09::parse(&crate::read_file("examples", 2022, 9)).unwrap();
+        assert_eq!(Day09::part_one(&input), Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day09::parse(&crate::read_file("examples", 2022, 9)).unwrap();
+        assert_eq!(Day09::part_two(&input), Some(1));
+    }
+}
@@ -0,0 +1,140 @@
+use std::str::FromStr;
+
+use crate::error::{parse_lines, SolveError};
+use crate::helpers::ocr::read_letters;
+use crate::helpers::vm::{Instruction, Vm};
+use crate::helpers::Grid;
+use crate::params::Params;
+use crate::Solution;
+
+/// The register holding X, the sprite's middle position.
+const X: usize = 0;
+
+/// Runs `ops`, calling `observer` with the number of every cycle and the value of X during it.
+fn run(ops: &[Op], mut observer: impl FnMut(u64, i64)) {
+    Vm::new(ops, vec![1]).run(|vm| observer(vm.clock(), vm.register(X)));
+}
+
+/// The pixels the CRT draws while running `ops`, lit where the sprite covers the beam.
+fn screen(ops: &[Op]) -> Grid<bool> {
+    let mut screen = Grid::new(40, 6, false);
+    run(ops, |cycle, x| {
+        let pixel = (cycle - 1) as usize;
+        let pos = [pixel % 40, pixel / 40];
+        if screen.contains(pos) {
+            screen[pos] = (pos[0] as i64).abs_diff(x) <= 1;
+        }
+    });
+    screen
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Op {
+    Noop,
+    Addx(i64),
+}
+
+impl Instruction for Op {
+    fn cycles(&self) -> u64 {
+        match self {
+            Op::Noop => 1,
+            Op::Addx(_) => 2,
+        }
+    }
+
+    fn execute(&self, registers: &mut [i64]) -> Option<isize> {
+        if let Op::Addx(n) = self {
+            registers[X] += n;
+        }
+        None
+    }
+}
+
+impl FromStr for Op {
+    type Err = SolveError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(" ").collect();
+        match parts[..] {
+            ["noop"] => Ok(Op::Noop),
+            ["addx", n] => Ok(Op::Addx(n.parse()?)),
+            _ => Err(format!("unknown op \"{}\"", s).into()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Program {
+    ops: Vec<Op>,
+    /// The cycles whose signal strength part one adds up.
+    cycles: Vec<u64>,
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 10;
+
+    type Input = Program;
+    type PartOne = i64;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, SolveError> {
+        Ok(Program {
+            ops: parse_lines(input, str::parse)?,
+            cycles: params.get_list("cycles", &[20, 60, 100, 140, 180, 220])?,
+        })
+    }
+
+    fn part_one(program: &Self::Input) -> Option<i64> {
+        let mut result = 0;
+        run(&program.ops, |cycle, x| {
+            if program.cycles.contains(&cycle) {
+                result += cycle as i64 * x;
+            }
+        });
+        Some(result)
+    }
+
+    fn part_two(program: &Self::Input) -> Option<String> {
+        read_letters(&screen(&program.ops))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::parse_file::<Day10>("examples").unwrap();
+        assert_eq!(Day10::part_one(&input), Some(13140));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::parse_file::<Day10>("examples").unwrap();
+        let expected = [
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ];
+        let rendered = screen(&input.ops).render(|&lit| if lit { '#' } else { '.' });
+        assert_eq!(rendered, expected.join("\n"));
+        // The example draws stripes rather than letters
+        assert_eq!(Day10::part_two(&input), None);
+    }
+
+    #[test]
+    fn test_unknown_op() {
+        let e = Day10::parse("noop\naddx 3\nmulx 2\n").unwrap_err();
+        assert_eq!(e.line, Some(3));
+    }
+}
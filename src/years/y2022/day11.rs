@@ -0,0 +1,175 @@
+use std::collections::VecDeque;
+
+use crate::error::SolveError;
+use crate::helpers::parse::{list, parse_blocks, scan, value};
+use crate::Solution;
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    operation: Op,
+    test: u64,
+    if_true: usize,
+    if_false: usize,
+    inspected: u64,
+}
+
+#[derive(Debug, Clone)]
+pub enum Op {
+    Mul(u64),
+    Add(u64),
+    Square,
+}
+
+const MONKEY: &str = concat!(
+    "Monkey {}:\n",
+    "  Starting items: {}\n",
+    "  Operation: new = old {} {}\n",
+    "  Test: divisible by {}\n",
+    "    If true: throw to monkey {}\n",
+    "    If false: throw to monkey {}",
+);
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let count = input.trim_end().split("\n\n").count();
+        parse_blocks(input, |m| {
+            let (_, items, operator, operand, test, if_true, if_false): (
+                usize,
+                String,
+                char,
+                String,
+                u64,
+                usize,
+                usize,
+            ) = scan(MONKEY, m)?;
+            let items = list(&items, ", ").map_err(|e| e.at_line(2))?.into();
+            let operation = match (operator, operand.as_str()) {
+                ('*', "old") => Op::Square,
+                ('*', n) => Op::Mul(value(n).map_err(|e| e.at_line(3))?),
+                ('+', n) => Op::Add(value(n).map_err(|e| e.at_line(3))?),
+                _ => {
+                    let message = format!("unrecognised operation \"{} {}\"", operator, operand);
+                    return Err(SolveError::new(message).at_line(3));
+                }
+            };
+            if test == 0 {
+                return Err(SolveError::new("cannot test divisibility by 0").at_line(4));
+            }
+            for (target, line) in [(if_true, 5), (if_false, 6)] {
+                if target >= count {
+                    let message = format!("there is no monkey {} to throw to", target);
+                    return Err(SolveError::new(message).at_line(line));
+                }
+            }
+
+            Ok(Monkey {
+                items,
+                operation,
+                test,
+                if_true,
+                if_false,
+                inspected: 0,
+            })
+        })
+    }
+
+    fn part_one(monkeys: &Self::Input) -> Option<u64> {
+        let mut monkeys = monkeys.clone();
+        for _ in 0..20 {
+            for i in 0..monkeys.len() {
+                let if_true = monkeys[i].if_true;
+                let if_false = monkeys[i].if_false;
+                while !monkeys[i].items.is_empty() {
+                    let mut worry = monkeys[i].items.pop_front().unwrap();
+                    worry = match monkeys[i].operation {
+                        Op::Add(n) => worry + n,
+                        Op::Mul(n) => worry * n,
+                        Op::Square => worry * worry,
+                    };
+                    worry /= 3;
+                    if worry % monkeys[i].test == 0 {
+                        monkeys[if_true].items.push_back(worry);
+                    } else {
+                        monkeys[if_false].items.push_back(worry);
+                    }
+                    monkeys[i].inspected += 1;
+                }
+            }
+        }
+        let mut inspected_counts = monkeys.iter().map(|m| m.inspected).collect::<Vec<u64>>();
+        inspected_counts.sort_by(|a, b| b.cmp(a));
+        Some(inspected_counts[0] * inspected_counts[1])
+    }
+
+    fn part_two(monkeys: &Self::Input) -> Option<u64> {
+        let mut monkeys = monkeys.clone();
+        // We need to avoid levels of worry growing unbounded and overflowing.
+        // Since all tests are for divisibility and we don't care about absolute worry levels,
+        // we can mod everything by the product of all of the divisibility test values and
+        // preserve the results of those tests.
+        // e.g. if tests are "divisible by 2" and "divisible by 3", mod everything by 6.
+        // 15 => false, true
+        // 15 % 6 = 3 => false, true
+        let field_bound = monkeys.iter().map(|m| m.test).product::<u64>();
+        for _ in 0..10000 {
+            for i in 0..monkeys.len() {
+                let if_true = monkeys[i].if_true;
+                let if_false = monkeys[i].if_false;
+                while !monkeys[i].items.is_empty() {
+                    let mut worry = monkeys[i].items.pop_front().unwrap();
+                    worry = match monkeys[i].operation {
+                        Op::Add(n) => worry + n,
+                        Op::Mul(n) => worry * n,
+                        Op::Square => worry * worry,
+                    };
+                    worry %= field_bound;
+                    if worry % monkeys[i].test == 0 {
+                        monkeys[if_true].items.push_back(worry);
+                    } else {
+                        monkeys[if_false].items.push_back(worry);
+                    }
+                    monkeys[i].inspected += 1;
+                }
+            }
+        }
+        let mut inspected_counts = monkeys.iter().map(|m| m.inspected).collect::<Vec<u64>>();
+        inspected_counts.sort_by(|a, b| b.cmp(a));
+        Some(inspected_counts[0] * inspected_counts[1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day11::parse(&crate::read_file("examples", 2022, 11)).unwrap();
+        assert_eq!(Day11::part_one(&input), Some(10605));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day11::parse(&crate::read_file("examples", 2022, 11)).unwrap();
+        assert_eq!(Day11::part_two(&input), Some(2713310158));
+    }
+
+    #[test]
+    fn test_malformed() {
+        let input = crate::read_file("examples", 2022, 11);
+        let e = Day11::parse(&input.replacen("by 23", "by 0", 1)).unwrap_err();
+        assert_eq!(e.line, Some(4));
+        let e = Day11::parse(&input.replacen("monkey 0", "monkey 4", 1)).unwrap_err();
+        assert_eq!(e.line, Some(13));
+    }
+}
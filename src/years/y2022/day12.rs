@@ -0,0 +1,67 @@
+use crate::error::SolveError;
+use crate::helpers::search::bfs;
+use crate::helpers::Grid;
+use crate::Solution;
+
+fn potential_steps(heights: &Grid<u8>, position: [usize; 2]) -> impl Iterator<Item = [usize; 2]> + '_ {
+    let pos_height = heights[position];
+    heights
+        .neighbours4(position)
+        .filter(move |step| heights[*step] <= pos_height + 1)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 12;
+
+    type Input = (Grid<u8>, [usize; 2], [usize; 2]);
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let map = Grid::parse(input, |c| match c {
+            'S' | 'E' | 'a'..='z' => Ok(c),
+            _ => Err(format!("unexpected '{}'", c).into()),
+        })?;
+        let start_position = map.position(|c| *c == 'S').ok_or("there is no start")?;
+        let end_position = map.position(|c| *c == 'E').ok_or("there is no end")?;
+        let heights = map.map(|c| match c {
+            'S' => 0,
+            'E' => 25,
+            _ => *c as u8 - b'a',
+        });
+        Ok((heights, start_position, end_position))
+    }
+
+    fn part_one(input: &Self::Input) -> Option<u32> {
+        let (heights, start, end) = input;
+        let path = bfs([*start], |p| potential_steps(heights, *p), |p| p == end)?;
+        Some(path.cost as u32)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<u32> {
+        let (heights, _, end) = input;
+        let starts = heights.iter().filter(|(_, h)| **h == 0).map(|(p, _)| p);
+        let path = bfs(starts, |p| potential_steps(heights, *p), |p| p == end)?;
+        Some(path.cost as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day12::parse(&crate::read_file("examples", 2022, 12)).unwrap();
+        assert_eq!(Day12::part_one(&input), Some(31));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day12::parse(&crate::read_file("examples", 2022, 12)).unwrap();
+        assert_eq!(Day12::part_two(&input), Some(29));
+    }
+}
@@ -0,0 +1,148 @@
+use std::{cmp::Ordering, str};
+
+use crate::error::{parse_blocks, SolveError};
+use crate::Solution;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Item {
+    Integer(u8),
+    List(Vec<Item>),
+}
+
+fn parse_list(cs: &Vec<u8>, pos: &mut usize) -> Result<Item, SolveError> {
+    let mut result: Vec<Item> = vec![];
+    if cs.get(*pos) != Some(&b'[') {
+        return Err(format!("expected a list at column {}", *pos + 1).into());
+    }
+    *pos += 1;
+    loop {
+        match cs.get(*pos) {
+            Some(b']') => break,
+            Some(b'[') => {
+                let item = parse_list(cs, pos)?;
+                result.push(item);
+                *pos += 1;
+            }
+            Some(b',') => *pos += 1,
+            Some(b'0'..=b'9') => {
+                let start = *pos;
+                while cs.get(*pos).is_some_and(u8::is_ascii_digit) {
+                    *pos += 1;
+                }
+                let int_slice = &cs[start..*pos];
+                let int_str = str::from_utf8(int_slice).unwrap();
+                let item = int_str.parse::<u8>()?;
+                result.push(Item::Integer(item));
+            }
+            Some(b) => {
+                return Err(format!("unexpected '{}' at column {}", *b as char, *pos + 1).into())
+            }
+            None => return Err("list is never closed".into()),
+        }
+    }
+    Ok(Item::List(result))
+}
+
+fn compare(left: &Item, right: &Item) -> Ordering {
+    match (left, right) {
+        (Item::Integer(i), Item::List(_)) => {
+            let new_list = Item::List(vec![Item::Integer(*i)]);
+            compare(&new_list, right)
+        }
+        (Item::List(_), Item::Integer(i)) => {
+            let new_list = Item::List(vec![Item::Integer(*i)]);
+            compare(left, &new_list)
+        }
+        (Item::Integer(l), Item::Integer(r)) => {
+            l.cmp(r)
+        }
+        (Item::List(l), Item::List(r)) => {
+            for i in 0..l.len() {
+                if i >= r.len() {
+                    return Ordering::Greater;
+                }
+                let result = compare(&l[i], &r[i]);
+                match result {
+                    Ordering::Equal => {}
+                    _ => return result,
+                }
+            }
+            if l.len() < r.len() {
+                return Ordering::Less;
+            }
+            Ordering::Equal
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 13;
+
+    type Input = Vec<(Item, Item)>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_blocks(input, |pair| {
+            let (left, right) = pair.split_once("\n").ok_or("expected a pair of packets")?;
+            let left_bytes = left.bytes().collect();
+            let right_bytes = right.bytes().collect();
+            let mut left_pos = 0;
+            let mut right_pos = 0;
+            Ok((
+                parse_list(&left_bytes, &mut left_pos).map_err(|e| e.at_line(1))?,
+                parse_list(&right_bytes, &mut right_pos).map_err(|e| e.at_line(2))?,
+            ))
+        })
+    }
+
+    fn part_one(pairs: &Self::Input) -> Option<u32> {
+        let mut result = 0;
+        for (index, (left, right)) in (1..).zip(pairs) {
+            if compare(left, right) == Ordering::Less {
+                result += index;
+            }
+        }
+        Some(result)
+    }
+
+    fn part_two(pairs: &Self::Input) -> Option<u32> {
+        let mut packets = vec![];
+        for (left, right) in pairs {
+            packets.push(left.clone());
+            packets.push(right.clone());
+        }
+        let divider_1 = Item::List(vec![Item::List(vec![Item::Integer(2)])]);
+        let divider_2 = Item::List(vec![Item::List(vec![Item::Integer(6)])]);
+        packets.push(divider_1.clone());
+        packets.push(divider_2.clone());
+        packets.sort_by(compare);
+        let mut result = 1;
+        for (i, packet) in packets.iter().enumerate() {
+            if *packet == divider_1 || *packet == divider_2 {
+                result *= i + 1;
+            }
+        }
+        Some(result as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day13::parse(&crate::read_file("examples", 2022, 13)).unwrap();
+        assert_eq!(Day13::part_one(&input), Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day13::parse(&crate::read_file("examples", 2022, 13)).unwrap();
+        assert_eq!(Day13::part_two(&input), Some(140));
+    }
+}
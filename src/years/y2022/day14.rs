@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use crate::error::{parse_lines, SolveError};
+use crate::Solution;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Item {
+    Rock,
+    Sand,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 14;
+
+    type Input = HashMap<[usize; 2], Item>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let mut result = HashMap::new();
+        let paths = parse_lines(input, |line| {
+            let path = line
+                .split(" -> ")
+                .map(|s| {
+                    let (x, y) = s
+                        .split_once(",")
+                        .ok_or_else(|| format!("expected a point, got \"{}\"", s))?;
+                    Ok((x.parse::<usize>()?, y.parse::<usize>()?))
+                })
+                .collect::<Result<Vec<(usize, usize)>, SolveError>>()?;
+            if path.len() < 2 {
+                return Err(format!("expected a path of at least two points, got \"{}\"", line).into());
+            }
+            if let Some(pair) = path.windows(2).find(|p| p[0].0 != p[1].0 && p[0].1 != p[1].1) {
+                let message = format!("{:?} to {:?} is not a straight line", pair[0], pair[1]);
+                return Err(message.into());
+            }
+            Ok(path)
+        })?;
+        for pairs in paths {
+            for pair in pairs.windows(2) {
+                let (left, right) = (pair[0], pair[1]);
+                if left.0 == right.0 {
+                    if left.1 <= right.1 {
+                        for y in left.1..right.1 + 1 {
+                            result.insert([left.0, y], Item::Rock);
+                        }
+                    } else {
+                        for y in right.1..left.1 + 1 {
+                            result.insert([left.0, y], Item::Rock);
+                        }
+                    }
+                } else {
+                    if left.0 <= right.0 {
+                        for x in left.0..right.0 + 1 {
+                            result.insert([x, left.1], Item::Rock);
+                        }
+                    } else {
+                        for x in right.0..left.0 + 1 {
+                            result.insert([x, left.1], Item::Rock);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    fn part_one(map: &Self::Input) -> Option<u32> {
+        let mut map = map.clone();
+        let abyss = map.keys().max_by_key(|v| v[1]).unwrap()[1];
+        let mut result = 0;
+        loop {
+            let mut sand_pos = [500, 0];
+            loop {
+                if sand_pos[1] >= abyss {
+                    return Some(result);
+                }
+                let mut done = true;
+                for pos in [
+                    [sand_pos[0], sand_pos[1] + 1],
+                    [sand_pos[0] - 1, sand_pos[1] + 1],
+                    [sand_pos[0] + 1, sand_pos[1] + 1],
+                ] {
+                    if !map.contains_key(&pos) {
+                        sand_pos = pos;
+                        done = false;
+                        break;
+                    }
+                }
+                if done {
+                    break;
+                }
+            }
+            map.insert(sand_pos, Item::Sand);
+            result += 1;
+        }
+    }
+
+    fn part_two(map: &Self::Input) -> Option<u32> {
+        let mut map = map.clone();
+        let floor = map.keys().max_by_key(|v| v[1]).unwrap()[1] + 2;
+        let mut result = 0;
+        loop {
+            let mut sand_pos = [500, 0];
+            if map.contains_key(&sand_pos) {
+                break; // done
+            }
+            loop {
+                if sand_pos[1] == floor - 1 {
+                    break;
+                }
+                let mut done = true;
+                for pos in [
+                    [sand_pos[0], sand_pos[1] + 1],
+                    [sand_pos[0] - 1, sand_pos[1] + 1],
+                    [sand_pos[0] + 1, sand_pos[1] + 1],
+                ] {
+                    if !map.contains_key(&pos) {
+                        sand_pos = pos;
+                        done = false;
+                        break;
+                    }
+                }
+                if done {
+                    break;
+                }
+            }
+            map.insert(sand_pos, Item::Sand);
+            result += 1;
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day14::parse(&crate::read_file("examples", 2022, 14)).unwrap();
+        assert_eq!(Day14::part_one(&input), Some(24));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day14::parse(&crate::read_file("examples", 2022, 14)).unwrap();
+        assert_eq!(Day14::part_two(&input), Some(93));
+    }
+
+    #[test]
+    fn test_malformed() {
+        for path in ["500,4", "498,4 -> 500,6"] {
+            let e = Day14::parse(&format!("503,4 -> 502,4\n{}", path)).unwrap_err();
+            assert_eq!(e.line, Some(2));
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::error::SolveError;
+use crate::helpers::interval::IntervalSet;
+use crate::helpers::parse::scan_lines;
+use crate::params::Params;
+use crate::Solution;
+
+fn manhattan_distance(a: &[isize; 2], b: &[isize; 2]) -> isize {
+    (a[0] - b[0]).abs() + (a[1] - b[1]).abs()
+}
+
+/// The x positions on row `y` that are closer to a sensor than its beacon is.
+fn coverage(items: &[([isize; 2], [isize; 2])], y: isize) -> IntervalSet<isize> {
+    items
+        .iter()
+        .filter_map(|(sensor, beacon)| {
+            let xdist = manhattan_distance(sensor, beacon) - (sensor[1] - y).abs();
+            (xdist >= 0).then(|| sensor[0] - xdist..=sensor[0] + xdist)
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct Sensors {
+    items: Vec<([isize; 2], [isize; 2])>,
+    /// The row part one counts covered positions in.
+    row: isize,
+    /// The distress beacon is at most this far along either axis.
+    max: isize,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 15;
+
+    type Input = Sensors;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, SolveError> {
+        let pattern = "Sensor at x={}, y={}: closest beacon is at x={}, y={}";
+        let items = scan_lines(input, pattern)?;
+        Ok(Sensors {
+            items: items
+                .into_iter()
+                .map(|(sx, sy, bx, by)| ([sx, sy], [bx, by]))
+                .collect(),
+            row: params.get("row", 2000000)?,
+            max: params.get("max", 4000000)?,
+        })
+    }
+
+    fn part_one(sensors: &Self::Input) -> Option<u64> {
+        let y = sensors.row;
+        let mut covered = coverage(&sensors.items, y);
+        for (_, beacon) in &sensors.items {
+            if beacon[1] == y {
+                covered.remove(beacon[0]..=beacon[0]);
+            }
+        }
+        Some(covered.len() as u64)
+    }
+
+    fn part_two(sensors: &Self::Input) -> Option<u64> {
+        let ymax = sensors.max;
+        for y in 0..ymax + 1 {
+            // The distress beacon is the only position in the area that no sensor covers
+            if let Some(gap) = coverage(&sensors.items, y).gaps(0..=ymax).ranges().next() {
+                return Some((*gap.start() as u64) * 4000000 + (y as u64));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::parse_file::<Day15>("examples").unwrap();
+        assert_eq!(Day15::part_one(&input), Some(26));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::parse_file::<Day15>("examples").unwrap();
+        assert_eq!(Day15::part_two(&input), Some(56000011));
+    }
+}
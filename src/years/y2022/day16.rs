@@ -0,0 +1,245 @@
+use std::collections::{HashMap, HashSet};
+use std::iter;
+
+use crate::error::{parse_lines, SolveError};
+use crate::helpers::graph::CompressedGraph;
+use crate::helpers::memo::Memo;
+use crate::helpers::parse::scan;
+use crate::{memo, Solution};
+
+#[derive(Debug)]
+pub struct Map {
+    /// Valve AA, where we start, followed by every valve worth opening.
+    valves: CompressedGraph<[u8; 2]>,
+    rates: Vec<usize>,
+}
+
+impl Map {
+    /// The valves with flow that are not yet open in `on`, with their distance from `from`.
+    fn closed_valves(&self, from: usize, on: u64) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.valves.len())
+            .filter(move |to| self.rates[*to] != 0 && on & self.valves.bit(*to) == 0)
+            .filter_map(move |to| Some((to, self.valves.distance(from, to)?)))
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct State1 {
+    on: u64,
+    time: usize,
+    pos: usize,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct State2 {
+    on: u64,
+    my_pos: usize,
+    my_time: usize,
+    el_pos: usize,
+    el_time: usize,
+}
+
+fn best1(map: &Map, state: State1, memo: &mut Memo<State1, usize>) -> usize {
+    memo!(memo, state => {
+        let mut result = 0;
+        for (next, distance) in map.closed_valves(state.pos, state.on) {
+            // move then activate valve
+            let time = state.time + distance + 1;
+            if time >= 30 {
+                continue;
+            }
+            let new_state = State1 {
+                on: state.on | map.valves.bit(next),
+                time,
+                pos: next,
+            };
+            let best = map.rates[next] * (30 - time) + best1(map, new_state, memo);
+            result = result.max(best);
+        }
+        result
+    })
+}
+
+fn best2(map: &Map, state: State2) -> usize {
+    if state.my_time >= 25 && state.el_time >= 25 {
+        return 0;
+    }
+    let mut result = 0;
+
+    if state.my_time <= state.el_time {
+        // I move
+        for (my_next, my_distance) in map.closed_valves(state.my_pos, state.on) {
+            if state.my_time + my_distance >= 25 {
+                continue;
+            }
+            let my_flow_total = map.rates[my_next] * (26 - (state.my_time + my_distance + 1));
+            let mut new_state = state.clone();
+            new_state.on |= map.valves.bit(my_next);
+            new_state.my_time += my_distance + 1;
+            new_state.my_pos = my_next;
+            let best = my_flow_total + best2(map, new_state);
+            result = result.max(best);
+        }
+    } else {
+        // elephant move
+        for (el_next, el_distance) in map.closed_valves(state.el_pos, state.on) {
+            if state.el_time + el_distance >= 25 {
+                continue;
+            }
+            let el_flow_total = map.rates[el_next] * (26 - (state.el_time + el_distance + 1));
+            let mut new_state = state.clone();
+            new_state.on |= map.valves.bit(el_next);
+            new_state.el_time += el_distance + 1;
+            new_state.el_pos = el_next;
+            let best = el_flow_total + best2(map, new_state);
+            result = result.max(best);
+        }
+    }
+    result
+}
+
+fn valve_name(valve: [u8; 2]) -> String {
+    format!("{}{}", (valve[0] + b'A') as char, (valve[1] + b'A') as char)
+}
+
+fn parse_valve(name: &str) -> Result<[u8; 2], SolveError> {
+    match name.as_bytes() {
+        [a @ b'A'..=b'Z', b @ b'A'..=b'Z'] => Ok([a - b'A', b - b'A']),
+        _ => Err(format!("expected a two letter valve, got \"{}\"", name).into()),
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 16;
+
+    type Input = Map;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let valves = parse_lines(input, |line| {
+            let (valve, rate, tunnels): (String, usize, String) =
+                scan("Valve {} has flow rate={}; {}", line)?;
+            // Either "tunnel leads to valve AA" or "tunnels lead to valves AA, BB"
+            let targets = tunnels
+                .split_once("valve")
+                .map(|(_, targets)| targets.trim_start_matches('s').trim())
+                .ok_or_else(|| format!("expected the valves tunnels lead to, got \"{}\"", tunnels))?
+                .split(", ")
+                .map(parse_valve)
+                .collect::<Result<Vec<[u8; 2]>, _>>()?;
+            Ok((parse_valve(&valve)?, rate, targets))
+        })?;
+        let known: HashSet<[u8; 2]> = valves.iter().map(|(valve, _, _)| *valve).collect();
+        for (i, (_, _, targets)) in valves.iter().enumerate() {
+            if let Some(target) = targets.iter().find(|v| !known.contains(*v)) {
+                let message = format!("tunnel leads to unknown valve {}", valve_name(*target));
+                return Err(SolveError::new(message).at_line(i + 1));
+            }
+        }
+        let start = [0, 0];
+        if !known.contains(&start) {
+            return Err("there is no valve AA to start from".into());
+        }
+        // The valves that are opened are kept as bits of a u64, along with the start
+        let mut kept = 1;
+        for (i, (valve, rate, _)) in valves.iter().enumerate() {
+            if *rate != 0 && *valve != start {
+                kept += 1;
+                if kept > 64 {
+                    let message = "only 63 valves other than AA can have a flow rate";
+                    return Err(SolveError::new(message).at_line(i + 1));
+                }
+            }
+        }
+        let worth_opening: Vec<[u8; 2]> = valves
+            .iter()
+            .filter(|(_, rate, _)| *rate != 0)
+            .map(|(v, _, _)| *v)
+            .collect();
+        let rates: HashMap<[u8; 2], usize> = valves.iter().map(|(v, rate, _)| (*v, *rate)).collect();
+        let tunnels: HashMap<[u8; 2], Vec<[u8; 2]>> =
+            valves.into_iter().map(|(v, _, targets)| (v, targets)).collect();
+
+        // Search the tunnels to cut the graph down to only distances between non-zero valves
+        let valves = CompressedGraph::new(iter::once(start).chain(worth_opening), |v| {
+            tunnels[v].clone()
+        });
+        for from in 0..valves.len() {
+            if let Some(to) = (1..valves.len()).find(|to| valves.distance(from, *to).is_none()) {
+                let (from, to) = (valve_name(*valves.node(from)), valve_name(*valves.node(to)));
+                return Err(format!("valve {} cannot be reached from {}", to, from).into());
+            }
+        }
+        let rates = valves.nodes().iter().map(|v| rates[v]).collect();
+
+        Ok(Map { valves, rates })
+    }
+
+    fn part_one(map: &Self::Input) -> Option<usize> {
+        let mut memo = Memo::new();
+        let state = State1 {
+            on: 0,
+            time: 0,
+            pos: 0,
+        };
+        let result = best1(map, state, &mut memo);
+        memo.report("day 16 part one");
+        Some(result)
+    }
+
+    fn part_two(map: &Self::Input) -> Option<usize> {
+        let state = State2 {
+            on: 0,
+            my_pos: 0,
+            my_time: 0,
+            el_pos: 0,
+            el_time: 0,
+        };
+        let result = best2(map, state);
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day16::parse(&crate::read_file("examples", 2022, 16)).unwrap();
+        assert_eq!(Day16::part_one(&input), Some(1651));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day16::parse(&crate::read_file("examples", 2022, 16)).unwrap();
+        assert_eq!(Day16::part_two(&input), Some(1707));
+    }
+
+    #[test]
+    fn test_bad_valve() {
+        let input = "Valve AA has flow rate=0; tunnels lead to valves BB\n\
+                     Valve B! has flow rate=13; tunnel leads to valve AA";
+        let e = Day16::parse(input).unwrap_err();
+        assert_eq!(e.line, Some(2));
+    }
+
+    #[test]
+    fn test_too_many_valves() {
+        // AA leads to 64 valves with a flow rate, one more than fits alongside it
+        let names: Vec<String> = (0..64).map(|i| valve_name([1 + i / 26, i % 26])).collect();
+        let mut input = format!("Valve AA has flow rate=0; tunnels lead to valves {}", names.join(", "));
+        for name in &names {
+            input += &format!("\nValve {} has flow rate=1; tunnel leads to valve AA", name);
+        }
+        let e = Day16::parse(&input).unwrap_err();
+        assert_eq!(e.line, Some(65));
+        let last = format!(", {}", names[63]);
+        let fits = input.rsplit_once('\n').unwrap().0.replace(&last, "");
+        assert!(Day16::parse(&fits).is_ok());
+    }
+}
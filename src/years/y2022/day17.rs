@@ -0,0 +1,199 @@
+//  0123456
+// |..@@@@.| 4
+// |.......| y = 3
+// |.......| y = 2
+// |.......| y = 1
+// +-------+
+
+// to get starting position, x+=2, y+=ymax+4
+// #### - [0, 0], [1, 0], [2, 0], [3, 0]
+
+// .#.
+// ###
+// .#. - [0, 1], [1, 2], [1, 1], [1, 0], [2, 1]
+
+// ..#
+// ..#
+// ### - [0, 0], [1, 0], [2, 0], [2, 1], [2, 2]
+
+// #
+// #
+// #
+// # - [0, 0], [0, 1], [0, 2], [0, 3]
+
+// ##
+// ## - [0, 0], [0, 1], [1, 0], [1, 1]
+
+// Move right: if any(x=6) pass else x+=1
+// Move left:  if any(x=0) pass else x-=1
+
+// Move down:
+// if any(y-1) are occupied or any(y=0) rest, else y-=1
+// rest:
+// Add positions to map, bump ymax to highest + 1
+
+use std::collections::HashSet;
+
+use crate::error::SolveError;
+use crate::helpers::cycle::CycleDetector;
+use crate::Solution;
+
+#[derive(Debug)]
+struct State {
+    map: HashSet<[i32; 2]>,
+    ymax: i32,
+    /// The highest rock in each column.
+    tops: [i32; 7],
+    rocks: u32,
+}
+
+impl State {
+    /// How far each column's highest rock is below the top of the tower.
+    fn surface(&self) -> [i32; 7] {
+        self.tops.map(|top| self.ymax - top)
+    }
+}
+
+fn try_jet(state: &State, shape: &mut [[i32; 2]], jet: u8) {
+    let xdiff = match jet {
+        b'<' => -1,
+        b'>' => 1,
+        _ => panic!("Unrecognised jet {}", jet),
+    };
+    let blocked = shape.iter().any(|pos| {
+        let new_pos = [pos[0] + xdiff, pos[1]];
+        new_pos[0] < 0 || new_pos[0] > 6 || state.map.contains(&new_pos)
+    });
+    if blocked {
+        return;
+    }
+    for pos in shape {
+        pos[0] += xdiff;
+    }
+}
+
+fn move_rock_down(state: &mut State, shape: &mut [[i32; 2]]) -> bool {
+    let can_move = shape.iter().all(|pos| {
+        let new_pos = [pos[0], pos[1] - 1];
+        new_pos[1] > 0 && !state.map.contains(&new_pos)
+    });
+    if can_move {
+        for pos in shape.iter_mut() {
+            pos[1] -= 1;
+        }
+    } else {
+        let mut new_ymax = 0;
+        for pos in shape.iter() {
+            state.map.insert(*pos);
+            let top = &mut state.tops[pos[0] as usize];
+            *top = (*top).max(pos[1]);
+            new_ymax = new_ymax.max(pos[1]);
+        }
+        state.ymax = state.ymax.max(new_ymax);
+        state.rocks += 1;
+        return true;
+    }
+    false
+}
+
+fn height_after_rocks(n: usize, all_jets: &[u8]) -> u64 {
+    let mut state = State {
+        map: HashSet::new(),
+        ymax: 0,
+        tops: [0; 7],
+        rocks: 0,
+    };
+    let all_shapes = [
+        vec![[0, 0], [1, 0], [2, 0], [3, 0]],
+        vec![[0, 1], [1, 2], [1, 1], [1, 0], [2, 1]],
+        vec![[0, 0], [1, 0], [2, 0], [2, 1], [2, 2]],
+        vec![[0, 0], [0, 1], [0, 2], [0, 3]],
+        vec![[0, 0], [0, 1], [1, 0], [1, 1]],
+    ];
+    let mut jet_index = 0;
+    // Where the next rock lands only depends on its shape, the next jet and the shape of the
+    // surface it falls onto, so once those repeat the height grows the same way every cycle
+    let mut detector = CycleDetector::new();
+    detector.push((0, jet_index, state.surface()));
+    let mut heights = vec![0];
+    while heights.len() <= n {
+        let shape_index = state.rocks as usize % all_shapes.len();
+        let mut shape = all_shapes[shape_index].clone();
+        for pos in shape.iter_mut() {
+            pos[0] += 2;
+            pos[1] += state.ymax + 4;
+        }
+        loop {
+            let jet = all_jets[jet_index];
+            jet_index = (jet_index + 1) % all_jets.len();
+            try_jet(&state, &mut shape, jet);
+            if move_rock_down(&mut state, &mut shape) {
+                break;
+            }
+        }
+
+        heights.push(state.ymax as i64);
+        let next_shape = state.rocks as usize % all_shapes.len();
+        if let Some(cycle) = detector.push((next_shape, jet_index, state.surface())) {
+            return cycle.extrapolate(&heights, n) as u64;
+        }
+    }
+    heights[n] as u64
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 17;
+
+    type Input = Vec<u8>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let jets: Vec<u8> = input.trim().bytes().collect();
+        if jets.is_empty() {
+            return Err(SolveError::new("there are no jets").at_line(1));
+        }
+        match jets.iter().find(|jet| **jet != b'<' && **jet != b'>') {
+            Some(jet) => {
+                Err(SolveError::new(format!("unrecognised jet '{}'", *jet as char)).at_line(1))
+            }
+            None => Ok(jets),
+        }
+    }
+
+    fn part_one(all_jets: &Self::Input) -> Option<u64> {
+        let result = height_after_rocks(2022, all_jets);
+        Some(result)
+    }
+
+    fn part_two(all_jets: &Self::Input) -> Option<u64> {
+        let result = height_after_rocks(1000000000000, all_jets);
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day17::parse(&crate::read_file("examples", 2022, 17)).unwrap();
+        assert_eq!(Day17::part_one(&input), Some(3068));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day17::parse(&crate::read_file("examples", 2022, 17)).unwrap();
+        assert_eq!(Day17::part_two(&input), Some(1514285714288));
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!(Day17::parse("\n").unwrap_err().line, Some(1));
+        assert_eq!(Day17::parse("<<>x>\n").unwrap_err().line, Some(1));
+    }
+}
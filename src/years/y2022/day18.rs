@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use crate::error::{parse_lines, SolveError};
+use crate::helpers::Point3;
+use crate::Solution;
+
+fn can_reach_edge(
+    cubes: &HashSet<Point3<i8>>,
+    outside: &mut HashSet<Point3<i8>>,
+    pockets: &mut HashSet<Point3<i8>>,
+    point: Point3<i8>,
+    min: i8,
+    max: i8,
+) -> bool {
+    if pockets.contains(&point) {
+        return false;
+    }
+    if outside.contains(&point) {
+        return true;
+    }
+    let mut visited: HashSet<Point3<i8>> = HashSet::new();
+    visited.insert(point);
+    loop {
+        let mut point_added = false;
+        for pos in &visited.clone() {
+            for adjacent in pos.neighbours6() {
+                if [adjacent.x, adjacent.y, adjacent.z]
+                    .iter()
+                    .any(|&c| c <= min || c >= max)
+                {
+                    // Reached an edge
+                    outside.extend(&visited);
+                    return true;
+                }
+                if visited.contains(&adjacent) || cubes.contains(&adjacent) {
+                    continue;
+                } else {
+                    point_added = true;
+                    visited.insert(adjacent);
+                }
+            }
+        }
+        if !point_added {
+            // In a pocket
+            pockets.extend(visited);
+            return false;
+        }
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 18;
+
+    type Input = HashSet<Point3<i8>>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let cubes = parse_lines(input, |s| {
+            let points = s
+                .split(",")
+                .map(|p| p.parse())
+                .collect::<Result<Vec<i8>, _>>()?;
+            match points[..] {
+                [x, y, z] => Ok(Point3::new(x, y, z)),
+                _ => Err(format!("expected a cube, got \"{}\"", s).into()),
+            }
+        })?;
+        Ok(cubes.into_iter().collect())
+    }
+
+    fn part_one(cubes: &Self::Input) -> Option<u32> {
+        let mut result = 0;
+        for cube in cubes {
+            for adjacent in cube.neighbours6() {
+                if !cubes.contains(&adjacent) {
+                    result += 1;
+                }
+            }
+        }
+        Some(result)
+    }
+
+    fn part_two(cubes: &Self::Input) -> Option<u32> {
+        let mut outside = HashSet::new();
+        let mut pockets = HashSet::new();
+        let mut result = 0;
+        let (min, max) = (-1, 23);
+        for cube in cubes {
+            for adjacent in cube.neighbours6() {
+                if !cubes.contains(&adjacent)
+                    && can_reach_edge(cubes, &mut outside, &mut pockets, adjacent, min, max)
+                {
+                    result += 1;
+                }
+            }
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day18::parse(&crate::read_file("examples", 2022, 18)).unwrap();
+        assert_eq!(Day18::part_one(&input), Some(64));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day18::parse(&crate::read_file("examples", 2022, 18)).unwrap();
+        assert_eq!(Day18::part_two(&input), Some(58));
+    }
+}
@@ -0,0 +1,217 @@
+use crate::error::{parse_lines, SolveError};
+use crate::helpers::branch_bound::{BranchAndBound, Search};
+use crate::helpers::parse::integers;
+use crate::Solution;
+
+#[derive(Debug)]
+pub struct Blueprint {
+    ore: u16,           // ore
+    clay: u16,          // ore
+    obsidian: [u16; 2], // ore, clay
+    geode: [u16; 2],    // ore, obsidian
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+struct State {
+    robots: [u16; 4],    // ore, clay, obsidian, geode
+    resources: [u16; 4], // ore, clay, obsidian, geode
+    time: u8,
+}
+
+fn next_to_build(robots: &[u16; 4], blueprint: &Blueprint) -> Vec<u8> {
+    let mut result = Vec::new();
+    if robots[2] > 0 {
+        result.push(3);
+    }
+    if robots[1] > 0 && robots[2] < blueprint.geode[1] {
+        result.push(2);
+    }
+    if robots[1] < blueprint.obsidian[1] {
+        result.push(1);
+    }
+    if robots[0] < blueprint.ore
+        || robots[0] < blueprint.clay
+        || robots[0] < blueprint.obsidian[0]
+        || robots[0] < blueprint.geode[0]
+    {
+        result.push(0);
+    }
+    result
+}
+
+fn can_build(blueprint: &Blueprint, resources: &[u16; 4], rock: u8) -> bool {
+    match rock {
+        0 => resources[0] >= blueprint.ore,
+        1 => resources[0] >= blueprint.clay,
+        2 => resources[0] >= blueprint.obsidian[0] && resources[1] >= blueprint.obsidian[1],
+        3 => resources[0] >= blueprint.geode[0] && resources[2] >= blueprint.geode[1],
+        _ => panic!("Unrecognised rock"),
+    }
+}
+impl State {
+    fn next_states(&self, blueprint: &Blueprint, finish: u8) -> Vec<State> {
+        let mut result = Vec::new();
+        let mut did_wait_state = false;
+
+        for next in next_to_build(&self.robots, blueprint) {
+            let mut time = self.time;
+            let mut new_resources = self.resources;
+            let mut new_robots = self.robots;
+
+            while !can_build(blueprint, &new_resources, next) && time < finish {
+                time += 1;
+                for (resource, robots) in new_resources.iter_mut().zip(self.robots) {
+                    *resource += robots;
+                }
+            }
+
+            if time >= finish {
+                if !did_wait_state {
+                    result.push(State {
+                        robots: new_robots,
+                        resources: new_resources,
+                        time,
+                    });
+                    did_wait_state = true;
+                }
+                continue;
+            }
+
+            time += 1;
+            for (resource, robots) in new_resources.iter_mut().zip(self.robots) {
+                *resource += robots;
+            }
+
+            if next == 0 {
+                // Create ore robot
+                new_resources[0] -= blueprint.ore;
+                new_robots[0] += 1;
+            } else if next == 1 {
+                // Create clay robot
+                new_resources[0] -= blueprint.clay;
+                new_robots[1] += 1;
+            } else if next == 2 {
+                // Create obsidian robot
+                new_resources[0] -= blueprint.obsidian[0];
+                new_resources[1] -= blueprint.obsidian[1];
+                new_robots[2] += 1;
+            } else if next == 3 {
+                // Create geodeo robot
+                new_resources[0] -= blueprint.geode[0];
+                new_resources[2] -= blueprint.geode[1];
+                new_robots[3] += 1;
+            }
+
+            let state = State {
+                robots: new_robots,
+                resources: new_resources,
+                time,
+            };
+            result.push(state);
+        }
+
+        result
+    }
+}
+
+/// Building robots for a blueprint until `finish`, to open as many geodes as possible.
+struct Factory<'a> {
+    blueprint: &'a Blueprint,
+    finish: u8,
+}
+
+impl Search for Factory<'_> {
+    type State = State;
+    type Score = u16;
+
+    fn expand(&self, state: &State) -> Vec<State> {
+        if state.time >= self.finish {
+            return vec![];
+        }
+        state.next_states(self.blueprint, self.finish)
+    }
+
+    fn score(&self, state: &State) -> u16 {
+        // The geode robots we have keep opening geodes until the end
+        state.resources[3] + state.robots[3] * (self.finish - state.time) as u16
+    }
+
+    fn bound(&self, state: &State) -> u16 {
+        // At best a new geode robot is built every minute from now on
+        let remaining = (self.finish - state.time) as u16;
+        self.score(state) + remaining * remaining.saturating_sub(1) / 2
+    }
+}
+
+fn best(blueprint: &Blueprint, finish: u8) -> u16 {
+    let state = State {
+        robots: [1, 0, 0, 0],
+        resources: [0, 0, 0, 0],
+        time: 0,
+    };
+    let factory = Factory { blueprint, finish };
+    BranchAndBound::new(&factory).memoize(true).run(state)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 19;
+
+    type Input = Vec<Blueprint>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input, |s| {
+            let numbers = integers::<u16>(s)?;
+            match numbers[..] {
+                [_, ore, clay, ob_ore, ob_clay, ge_ore, ge_ob] => Ok(Blueprint {
+                    ore,
+                    clay,
+                    obsidian: [ob_ore, ob_clay],
+                    geode: [ge_ore, ge_ob],
+                }),
+                _ => Err(format!("expected a blueprint with six costs, got \"{}\"", s).into()),
+            }
+        })
+    }
+
+    fn part_one(blueprints: &Self::Input) -> Option<usize> {
+        let mut result = 0;
+
+        for (i, blueprint) in blueprints.iter().enumerate() {
+            let blueprint_result = best(blueprint, 24);
+            result += (i + 1) * blueprint_result as usize;
+        }
+        Some(result)
+    }
+
+    fn part_two(blueprints: &Self::Input) -> Option<usize> {
+        let mut result = 1;
+
+        for blueprint in blueprints.iter().take(3) {
+            let blueprint_result = best(blueprint, 32);
+            result *= blueprint_result as usize;
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day19::parse(&crate::read_file("examples", 2022, 19)).unwrap();
+        assert_eq!(Day19::part_one(&input), Some(33));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day19::parse(&crate::read_file("examples", 2022, 19)).unwrap();
+        assert_eq!(Day19::part_two(&input), Some(62 * 56));
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use crate::error::{parse_lines, SolveError};
+use crate::Solution;
+
+fn find_index_of(nums: &[(i64, i64)], a: (i64, i64)) -> usize {
+    let mut i = 0;
+    while nums[i] != a {
+        i += 1;
+    }
+    i
+}
+
+fn mix(order: &[(i64, i64)], numbers: &mut [(i64, i64)]) {
+    for num in order {
+        let mut position = find_index_of(numbers, *num);
+        // If the length of the list is 7, it takes 6 moves
+        // in either direction to get back to where we started,
+        // so we only need to worry about the moves after that
+        // has happened n times.
+        let num_moves = num.0 % (numbers.len() as i64 - 1);
+        for _ in 0..num_moves.abs() {
+            let to = if num_moves < 0 {
+                (position as i64 - 1).rem_euclid(numbers.len() as i64) as usize
+            } else {
+                (position + 1) % numbers.len()
+            };
+            numbers.swap(position, to);
+            position = to;
+        }
+    }
+}
+
+fn get_result(numbers: &[(i64, i64)]) -> i64 {
+    let i = find_index_of(numbers, (0, 0));
+    [1000, 2000, 3000].iter().map(|val| numbers[(i + val) % numbers.len()].0).sum()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 20;
+
+    type Input = Vec<(i64, i64)>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let numbers = parse_lines(input, |s| Ok(s.trim().parse::<i64>()?))?;
+        let mut result: Vec<(i64, i64)> = vec![];
+        let mut counts = HashMap::new();
+        for number in numbers {
+            if let Some(count) = counts.get_mut(&number) {
+                *count += 1;
+                result.push((number, *count));
+            } else {
+                counts.insert(number, 0);
+                result.push((number, 0));
+            }
+        }
+        Ok(result)
+    }
+
+    fn part_one(order: &Self::Input) -> Option<i64> {
+        let mut numbers = order.clone();
+        mix(order, &mut numbers);
+        Some(get_result(&numbers))
+    }
+
+    fn part_two(order: &Self::Input) -> Option<i64> {
+        let mut order = order.clone();
+        order.iter_mut().for_each(|n| n.0 *= 811589153);
+        let mut numbers = order.clone();
+
+        for _ in 0..10 {
+            mix(&order, &mut numbers);
+        }
+        Some(get_result(&numbers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day20::parse(&crate::read_file("examples", 2022, 20)).unwrap();
+        assert_eq!(Day20::part_one(&input), Some(3));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day20::parse(&crate::read_file("examples", 2022, 20)).unwrap();
+        assert_eq!(Day20::part_two(&input), Some(1623178306));
+    }
+}
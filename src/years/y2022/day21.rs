@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+
+use crate::error::{parse_lines, SolveError};
+use crate::helpers::rational::Rational;
+use crate::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Operator {
+    fn apply(self, left: Rational, right: Rational) -> Option<Rational> {
+        match self {
+            Operator::Add => Some(left + right),
+            Operator::Sub => Some(left - right),
+            Operator::Mul => Some(left * right),
+            Operator::Div => left.checked_div(right),
+        }
+    }
+}
+
+/// What a monkey yells. Other monkeys are referred to by name while parsing, and by their
+/// position in the input after that.
+#[derive(Debug, Clone, Copy)]
+enum Job<M = usize> {
+    Number(i64),
+    Operation(M, Operator, M),
+}
+
+/// A monkey on the way from `root` to `humn`, combining the number that depends on `humn`
+/// with a number that doesn't.
+#[derive(Debug)]
+struct Step {
+    operator: Operator,
+    other: usize,
+    humn_left: bool,
+}
+
+#[derive(Debug)]
+pub struct Monkeys {
+    jobs: Vec<Job>,
+    names: Vec<String>,
+    index: HashMap<String, usize>,
+    /// Every monkey, after all the monkeys it listens to.
+    order: Vec<usize>,
+}
+
+impl Monkeys {
+    /// The exact number `monkey` yells, or `None` if it involves dividing by zero.
+    fn evaluate(&self, monkey: usize) -> Option<Rational> {
+        match self.jobs[monkey] {
+            Job::Number(n) => Some(Rational::from(n)),
+            Job::Operation(left, operator, right) => {
+                operator.apply(self.evaluate(left)?, self.evaluate(right)?)
+            }
+        }
+    }
+
+    /// Whether each monkey's number depends on `humn`'s.
+    fn depends_on(&self, humn: usize) -> Vec<bool> {
+        let mut depends = vec![false; self.jobs.len()];
+        for &monkey in &self.order {
+            depends[monkey] = match self.jobs[monkey] {
+                _ if monkey == humn => true,
+                Job::Number(_) => false,
+                Job::Operation(left, _, right) => depends[left] || depends[right],
+            };
+        }
+        depends
+    }
+
+    fn find(&self, name: &str) -> Result<usize, SolveError> {
+        self.index
+            .get(name)
+            .copied()
+            .ok_or_else(|| format!("there is no monkey called {}", name).into())
+    }
+
+    /// The side of `root`'s equation that doesn't depend on `humn`, and the monkeys below
+    /// `root` whose numbers do, from the top. Only works when `humn` is used once along the
+    /// way, and never as a divisor, so that each step can be undone to find it.
+    fn humn_path(&self) -> Result<(usize, Vec<Step>), SolveError> {
+        let error = |monkey: usize, message: &str| {
+            SolveError::new(format!("{} {}", self.names[monkey], message)).at_line(monkey + 1)
+        };
+        let (root, humn) = (self.find("root")?, self.find("humn")?);
+        let depends = self.depends_on(humn);
+        let Job::Operation(left, _, right) = self.jobs[root] else {
+            return Err(error(root, "must compare two monkeys"));
+        };
+        let (mut monkey, target) = match (depends[left], depends[right]) {
+            (true, false) => (left, right),
+            (false, true) => (right, left),
+            (true, true) => return Err(error(root, "has humn on both sides")),
+            (false, false) => return Err(error(root, "does not depend on humn")),
+        };
+
+        let mut path = vec![];
+        while monkey != humn {
+            let Job::Operation(left, operator, right) = self.jobs[monkey] else {
+                unreachable!("only operations can depend on humn");
+            };
+            let step = match (depends[left], depends[right], operator) {
+                (true, true, _) => return Err(error(monkey, "has humn on both sides")),
+                (false, true, Operator::Div) => {
+                    return Err(error(monkey, "divides by humn, which is not linear"))
+                }
+                (true, false, _) => Step {
+                    operator,
+                    other: right,
+                    humn_left: true,
+                },
+                _ => Step {
+                    operator,
+                    other: left,
+                    humn_left: false,
+                },
+            };
+            monkey = if step.humn_left { left } else { right };
+            path.push(step);
+        }
+        Ok((target, path))
+    }
+}
+
+/// Every monkey, after all the monkeys it listens to. Fails with a monkey whose number
+/// depends on itself.
+fn topological_order(jobs: &[Job]) -> Result<Vec<usize>, usize> {
+    fn visit(
+        jobs: &[Job],
+        monkey: usize,
+        order: &mut Vec<usize>,
+        done: &mut [bool],
+        visiting: &mut [bool],
+    ) -> Result<(), usize> {
+        if done[monkey] {
+            return Ok(());
+        }
+        if visiting[monkey] {
+            return Err(monkey);
+        }
+        visiting[monkey] = true;
+        if let Job::Operation(left, _, right) = jobs[monkey] {
+            visit(jobs, left, order, done, visiting)?;
+            visit(jobs, right, order, done, visiting)?;
+        }
+        done[monkey] = true;
+        order.push(monkey);
+        Ok(())
+    }
+
+    let mut order = vec![];
+    let mut done = vec![false; jobs.len()];
+    let mut visiting = vec![false; jobs.len()];
+    for monkey in 0..jobs.len() {
+        visit(jobs, monkey, &mut order, &mut done, &mut visiting)?;
+    }
+    Ok(order)
+}
+
+/// Undoes each step from the top, working out what its humn side must be equal to.
+fn solve_for_humn(monkeys: &Monkeys, target: usize, path: &[Step]) -> Option<i64> {
+    let mut value = monkeys.evaluate(target)?;
+    for step in path {
+        let other = monkeys.evaluate(step.other)?;
+        value = match (step.operator, step.humn_left) {
+            (Operator::Add, _) => value - other,
+            (Operator::Sub, true) => value + other,
+            (Operator::Sub, false) => other - value,
+            (Operator::Mul, _) => value.checked_div(other)?,
+            (Operator::Div, true) => value * other,
+            (Operator::Div, false) => unreachable!("humn_path rejects dividing by humn"),
+        };
+    }
+    value.to_integer()?.try_into().ok()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 21;
+
+    type Input = Monkeys;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let monkeys = parse_lines(input, |line| {
+            let (name, command) = line
+                .split_once(": ")
+                .ok_or_else(|| format!("expected a monkey and its job, got \"{}\"", line))?;
+            if let Ok(val) = command.parse::<i64>() {
+                return Ok((name.to_string(), Job::Number(val)));
+            }
+            let parts: Vec<&str> = command.split(" ").collect();
+            let operator = match parts[..] {
+                [_, "+", _] => Operator::Add,
+                [_, "-", _] => Operator::Sub,
+                [_, "*", _] => Operator::Mul,
+                [_, "/", _] => Operator::Div,
+                _ => {
+                    return Err(format!("expected a number or an operation, got \"{}\"", command).into())
+                }
+            };
+            Ok((
+                name.to_string(),
+                Job::Operation(parts[0].to_string(), operator, parts[2].to_string()),
+            ))
+        })?;
+
+        let names: Vec<&str> = monkeys.iter().map(|(name, _)| name.as_str()).collect();
+        let mut index = HashMap::new();
+        for (i, &name) in names.iter().enumerate() {
+            if index.insert(name, i).is_some() {
+                return Err(SolveError::new(format!("{} has two jobs", name)).at_line(i + 1));
+            }
+        }
+        let find = |name: &str| {
+            index
+                .get(name)
+                .copied()
+                .ok_or_else(|| SolveError::new(format!("unknown monkey \"{}\"", name)))
+        };
+        let jobs = monkeys
+            .iter()
+            .enumerate()
+            .map(|(i, (_, job))| {
+                let find = |name: &str| find(name).map_err(|e| e.at_line(i + 1));
+                Ok(match job {
+                    Job::Number(n) => Job::Number(*n),
+                    Job::Operation(left, operator, right) => {
+                        Job::Operation(find(left)?, *operator, find(right)?)
+                    }
+                })
+            })
+            .collect::<Result<Vec<Job>, SolveError>>()?;
+
+        let order = topological_order(&jobs).map_err(|monkey| {
+            SolveError::new(format!("{} depends on its own number", names[monkey]))
+                .at_line(monkey + 1)
+        })?;
+        let index = index
+            .into_iter()
+            .map(|(name, i)| (name.to_string(), i))
+            .collect();
+        let names = names.into_iter().map(String::from).collect();
+        Ok(Monkeys {
+            jobs,
+            names,
+            index,
+            order,
+        })
+    }
+
+    fn part_one(monkeys: &Self::Input) -> Option<i64> {
+        let root = *monkeys.index.get("root")?;
+        monkeys.evaluate(root)?.to_integer()?.try_into().ok()
+    }
+
+    fn part_two(monkeys: &Self::Input) -> Option<i64> {
+        Self::try_part_two(monkeys).ok().flatten()
+    }
+
+    /// Fails if the equation can't be solved by undoing one step at a time, which unlike a
+    /// division by zero or an answer that isn't a whole number depends only on the structure.
+    fn try_part_two(monkeys: &Self::Input) -> Result<Option<i64>, SolveError> {
+        let (target, path) = monkeys.humn_path()?;
+        Ok(solve_for_humn(monkeys, target, &path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day21::parse(&crate::read_file("examples", 2022, 21)).unwrap();
+        assert_eq!(Day21::part_one(&input), Some(152));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day21::parse(&crate::read_file("examples", 2022, 21)).unwrap();
+        assert_eq!(Day21::part_two(&input), Some(301));
+    }
+
+    #[test]
+    fn test_inexact_division() {
+        // humn / 4 * 3 = 6 gives humn = 8, but humn / 4 * 3 = 5 has no integer answer,
+        // where truncating 5 / 3 * 4 would have claimed 4
+        let input = "root: aaaa + bbbb\naaaa: cccc * dddd\ncccc: humn / eeee\nhumn: 1\ndddd: 3\neeee: 4\nbbbb: 6";
+        let monkeys = Day21::parse(input).unwrap();
+        assert_eq!(Day21::part_two(&monkeys), Some(8));
+        let monkeys = Day21::parse(&input.replace("bbbb: 6", "bbbb: 5")).unwrap();
+        assert_eq!(Day21::part_two(&monkeys), None);
+        assert_eq!(Day21::part_one(&monkeys), None);
+    }
+
+    #[test]
+    fn test_unsolvable() {
+        // Part one still works when humn can't be isolated for part two
+        let both = "root: aaaa + humn\naaaa: humn * bbbb\nbbbb: 2\nhumn: 1";
+        let square = "root: aaaa + bbbb\naaaa: humn * humn\nbbbb: 4\nhumn: 1";
+        let divisor = "root: aaaa + bbbb\naaaa: bbbb / humn\nbbbb: 4\nhumn: 1";
+        let missing = "root: aaaa + bbbb\naaaa: 1\nbbbb: 4";
+        for (input, total, line) in [
+            (both, 3, Some(1)),
+            (square, 5, Some(2)),
+            (divisor, 8, Some(2)),
+            (missing, 5, None),
+        ] {
+            let monkeys = Day21::parse(input).unwrap();
+            assert_eq!(Day21::part_one(&monkeys), Some(total));
+            assert_eq!(Day21::try_part_two(&monkeys).unwrap_err().line, line);
+        }
+        let monkeys = Day21::parse(both).unwrap();
+        let e = Day21::try_part_two(&monkeys).unwrap_err();
+        assert_eq!(e.message, "root has humn on both sides");
+
+        // The runner reports it rather than leaving part two unsolved
+        let e = crate::solve_parts::<Day21>(both, &Default::default()).unwrap_err();
+        assert_eq!((e.part, e.line), (Some(2), Some(1)));
+    }
+
+    #[test]
+    fn test_malformed() {
+        let unknown = "root: aaaa + humn\nhumn: 1";
+        assert_eq!(Day21::parse(unknown).unwrap_err().line, Some(1));
+        let cycle = "root: aaaa + humn\nhumn: 1\naaaa: root - humn";
+        assert_eq!(Day21::parse(cycle).unwrap_err().line, Some(1));
+    }
+}
@@ -0,0 +1,376 @@
+use std::collections::HashSet;
+
+use crate::error::SolveError;
+use crate::helpers::Point3;
+use crate::Solution;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Point(isize, isize);
+
+#[derive(Debug)]
+pub struct Map {
+    grid: Vec<Vec<char>>,
+    max_width: isize,
+}
+
+impl Map {
+    fn at(&self, pt: &Point) -> char {
+        if pt.1 >= self.grid.len() as isize
+            || pt.1 < 0
+            || pt.0 >= self.grid[pt.1 as usize].len() as isize
+            || pt.0 < 0
+        {
+            ' '
+        } else {
+            self.grid[pt.1 as usize][pt.0 as usize]
+        }
+    }
+}
+
+/// A face of the cube, with the directions its edges point in once the net is folded.
+#[derive(Debug)]
+struct Face {
+    /// Top left corner of the face on the map.
+    corner: Point,
+    normal: Point3<i32>,
+    /// The directions moving right and down on the map point in on this face.
+    right: Point3<i32>,
+    down: Point3<i32>,
+}
+
+impl Face {
+    /// Which way moving in each direction points, in the order of `State::direction`.
+    fn headings(&self) -> [Point3<i32>; 4] {
+        [self.right, self.down, -self.right, -self.down]
+    }
+}
+
+/// The map folded up into a cube.
+#[derive(Debug)]
+struct Cube {
+    size: isize,
+    faces: Vec<Face>,
+}
+
+impl Cube {
+    /// Folds any of the cube nets, or returns `None` if the map is not one.
+    fn fold(map: &Map) -> Option<Cube> {
+        let cells = map.grid.iter().flatten().filter(|c| **c != ' ').count();
+        let size = (1..).find(|size| 6 * size * size >= cells)?;
+        if 6 * size * size != cells {
+            return None;
+        }
+        let size = size as isize;
+        let corners: Vec<Point> = (0..map.grid.len() as isize / size)
+            .flat_map(|y| (0..map.max_width / size).map(move |x| Point(x * size, y * size)))
+            .filter(|corner| map.at(corner) != ' ')
+            .collect();
+        if corners.len() != 6 {
+            return None;
+        }
+
+        // Fold the faces away from us around the first one. Going over an edge, the direction
+        // we were heading in becomes the normal of the next face.
+        let mut faces = vec![Face {
+            corner: corners[0].clone(),
+            normal: Point3::new(0, 0, 1),
+            right: Point3::new(1, 0, 0),
+            down: Point3::new(0, 1, 0),
+        }];
+        let mut i = 0;
+        while i < faces.len() {
+            let Face {
+                corner,
+                normal,
+                right,
+                down,
+            } = &faces[i];
+            let (normal, right, down) = (*normal, *right, *down);
+            let next = [
+                (Point(corner.0 + size, corner.1), right, -normal, down),
+                (Point(corner.0, corner.1 + size), down, right, -normal),
+                (Point(corner.0 - size, corner.1), -right, normal, down),
+                (Point(corner.0, corner.1 - size), -down, right, normal),
+            ];
+            for (corner, normal, right, down) in next {
+                if corners.contains(&corner) && faces.iter().all(|f| f.corner != corner) {
+                    faces.push(Face {
+                        corner,
+                        normal,
+                        right,
+                        down,
+                    });
+                }
+            }
+            i += 1;
+        }
+        let normals: HashSet<Point3<i32>> = faces.iter().map(|f| f.normal).collect();
+        if faces.len() != 6 || normals.len() != 6 {
+            return None;
+        }
+        Some(Cube { size, faces })
+    }
+
+    /// Where stepping off the edge of a face from `position` in `direction` leads, and which
+    /// direction we are heading in once there.
+    fn wrap(&self, position: &Point, direction: isize) -> (Point, isize) {
+        let size = self.size;
+        let corner = Point(
+            position.0 - position.0.rem_euclid(size),
+            position.1 - position.1.rem_euclid(size),
+        );
+        let from = self.faces.iter().find(|f| f.corner == corner).unwrap();
+        let heading = from.headings()[direction as usize];
+        let to = self.faces.iter().find(|f| f.normal == heading).unwrap();
+        let new_direction = to.headings().iter().position(|h| *h == -from.normal).unwrap() as isize;
+
+        // How far along the edge we cross, measured along the same line on both faces
+        let (x, y) = (position.0 - from.corner.0, position.1 - from.corner.1);
+        let (along, edge) = if direction % 2 == 0 { (y, from.down) } else { (x, from.right) };
+        let new_edge = if new_direction % 2 == 0 { to.down } else { to.right };
+        let along = if edge == new_edge { along } else { size - 1 - along };
+        let (x, y) = match new_direction {
+            0 => (0, along),
+            1 => (along, 0),
+            2 => (size - 1, along),
+            _ => (along, size - 1),
+        };
+        (Point(to.corner.0 + x, to.corner.1 + y), new_direction)
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    position: Point,
+    direction: isize,
+}
+
+impl State {
+    fn new(map: &Map) -> State {
+        let mut pt = Point(0, 0);
+        while map.at(&pt) != '.' {
+            pt.0 += 1;
+        }
+        State {
+            position: pt,
+            direction: 0,
+        }
+    }
+
+    fn move_one_p1(&mut self, map: &Map) -> bool {
+        let mut new = self.position.clone();
+        let (xdiff, ydiff) = match self.direction {
+            0 => (1, 0),
+            1 => (0, 1),
+            2 => (-1, 0),
+            3 => (0, -1),
+            _ => panic!("Unknown direction {}", self.direction),
+        };
+        new.0 = (new.0 + xdiff).rem_euclid(map.max_width);
+        new.1 = (new.1 + ydiff).rem_euclid(map.grid.len() as isize);
+        while map.at(&new) == ' ' {
+            new.0 = (new.0 + xdiff).rem_euclid(map.max_width);
+            new.1 = (new.1 + ydiff).rem_euclid(map.grid.len() as isize);
+        }
+        if map.at(&new) == '#' {
+            false
+        } else {
+            self.position = new;
+            true
+        }
+    }
+
+    fn move_one_p2(&mut self, map: &Map, cube: &Cube) -> bool {
+        let mut new = self.position.clone();
+        let mut new_direction = self.direction;
+        let (xdiff, ydiff) = match self.direction {
+            0 => (1, 0),
+            1 => (0, 1),
+            2 => (-1, 0),
+            3 => (0, -1),
+            _ => panic!("Unknown direction {}", self.direction),
+        };
+        new.0 += xdiff;
+        new.1 += ydiff;
+        if map.at(&new) == ' ' {
+            (new, new_direction) = cube.wrap(&self.position, self.direction);
+        }
+        if map.at(&new) == '#' {
+            false
+        } else {
+            self.position = new;
+            self.direction = new_direction;
+            true
+        }
+    }
+
+    fn result(&self) -> isize {
+        let row = self.position.1 + 1;
+        let col = self.position.0 + 1;
+        1000 * row + 4 * col + self.direction
+    }
+}
+
+#[derive(Debug)]
+pub enum Command {
+    Move(isize),
+    Turn(isize),
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 22;
+
+    type Input = (Map, Vec<Command>);
+    type PartOne = isize;
+    type PartTwo = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let (raw_map, raw_commands) = input
+            .split_once("\n\n")
+            .ok_or("expected the map and the path to be separated by an empty line")?;
+        let line = raw_map.split("\n").count() + 2;
+        let at_line = |e: SolveError| e.at_line(line);
+        let grid: Vec<Vec<char>> = raw_map.split("\n").map(|s| s.chars().collect()).collect();
+        // The path starts on the leftmost open tile of the top row
+        if !grid[0].contains(&'.') {
+            return Err(SolveError::new("there is no open tile on the top row to start on").at_line(1));
+        }
+        let mut digit = String::new();
+        let mut commands = Vec::new();
+        for c in raw_commands.trim().chars() {
+            if c.is_alphabetic() {
+                commands.push(Command::Move(
+                    digit.parse().map_err(SolveError::from).map_err(at_line)?,
+                ));
+                digit.clear();
+                match c {
+                    'L' => commands.push(Command::Turn(-1)),
+                    'R' => commands.push(Command::Turn(1)),
+                    _ => return Err(at_line(format!("unrecognised direction '{}'", c).into())),
+                };
+            } else {
+                digit.push(c);
+            }
+        }
+        if !digit.is_empty() {
+            commands.push(Command::Move(
+                digit.parse().map_err(SolveError::from).map_err(at_line)?,
+            ));
+        }
+        let max_width = grid.iter().map(|l| l.len()).max().unwrap_or(0) as isize;
+        Ok((Map { grid, max_width }, commands))
+    }
+
+    fn part_one(input: &Self::Input) -> Option<isize> {
+        let (map, commands) = input;
+        let mut state = State::new(map);
+        for command in commands {
+            match command {
+                Command::Turn(val) => state.direction = (state.direction + val).rem_euclid(4),
+                Command::Move(val) => {
+                    for _ in 0..*val {
+                        if !state.move_one_p1(map) {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        Some(state.result())
+    }
+
+    fn part_two(input: &Self::Input) -> Option<isize> {
+        let (map, commands) = input;
+        let cube = Cube::fold(map)?;
+        let mut state = State::new(map);
+        for command in commands {
+            match command {
+                Command::Turn(val) => state.direction = (state.direction + val).rem_euclid(4),
+                Command::Move(val) => {
+                    for _ in 0..*val {
+                        if !state.move_one_p2(map, &cube) {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        Some(state.result())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::iter;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day22::parse(&crate::read_file("examples", 2022, 22)).unwrap();
+        assert_eq!(Day22::part_one(&input), Some(6032));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day22::parse(&crate::read_file("examples", 2022, 22)).unwrap();
+        assert_eq!(Day22::part_two(&input), Some(5031));
+    }
+
+    #[test]
+    fn test_malformed() {
+        for map in ["  ##\n ...", "\n..."] {
+            let e = Day22::parse(&format!("{}\n\n10R5", map)).unwrap_err();
+            assert_eq!(e.line, Some(1));
+        }
+    }
+
+    /// Every cell of a net with all faces `size` wide, and no walls.
+    fn open_net(layout: &[&str], size: usize) -> Map {
+        let grid: Vec<Vec<char>> = layout
+            .iter()
+            .flat_map(|row| {
+                let line: Vec<char> = row
+                    .chars()
+                    .flat_map(|c| iter::repeat_n(if c == '#' { '.' } else { ' ' }, size))
+                    .collect();
+                vec![line; size]
+            })
+            .collect();
+        let max_width = grid.iter().map(|l| l.len()).max().unwrap() as isize;
+        Map { grid, max_width }
+    }
+
+    #[test]
+    fn test_fold() {
+        let nets: [&[&str]; 3] = [
+            &["  #", "###", "  ##"],
+            &[" ##", " #", "##", "#"],
+            &[" #", "###", " #", " #"],
+        ];
+        for net in nets {
+            let map = open_net(net, 3);
+            let cube = Cube::fold(&map).unwrap();
+            // Walking four faces in a straight line always goes round the cube back to the start
+            for face in &cube.faces {
+                for direction in 0..4 {
+                    let mut state = State {
+                        position: Point(face.corner.0 + 1, face.corner.1),
+                        direction,
+                    };
+                    for _ in 0..12 {
+                        assert!(state.move_one_p2(&map, &cube));
+                    }
+                    assert_eq!(
+                        (state.position.0, state.position.1, state.direction),
+                        (face.corner.0 + 1, face.corner.1, direction)
+                    );
+                }
+            }
+        }
+        assert!(Cube::fold(&open_net(&["####", "##"], 3)).is_none());
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use crate::error::SolveError;
+use crate::helpers::{Grid, SparseGrid};
+use crate::Solution;
+
+fn do_round(points: &mut SparseGrid<()>, iteration: i32) -> bool {
+    let mut movers = HashMap::<[isize; 2], Vec<[isize; 2]>>::new();
+    let mut new_points = SparseGrid::with_capacity(points.len());
+    let mut any_moved = false;
+    // 0 = north, 1 = south, 2 = west, 3 = east
+    for pt in points.positions() {
+        let [x, y] = pt;
+        let n = [x, y - 1];
+        let ne = [x + 1, y - 1];
+        let e = [x + 1, y];
+        let se = [x + 1, y + 1];
+        let s = [x, y + 1];
+        let sw = [x - 1, y + 1];
+        let w = [x - 1, y];
+        let nw = [x - 1, y - 1];
+
+        if [n, ne, e, se, s, sw, w, nw]
+            .iter()
+            .all(|p| !points.contains(*p))
+        {
+            new_points.insert(pt, ());
+            continue;
+        }
+
+        let mut moved = false;
+        for i in 0..4 {
+            let direction = (iteration + i).rem_euclid(4);
+            let (checks, mv) = match direction {
+                0 => ([nw, n, ne], n),
+                1 => ([sw, s, se], s),
+                2 => ([sw, w, nw], w),
+                3 => ([ne, e, se], e),
+                _ => unreachable!(),
+            };
+            if checks.iter().all(|ch| !points.contains(*ch)) {
+                movers.entry(mv).or_insert(vec![]).push(pt);
+                moved = true;
+                break;
+            }
+        }
+        if !moved {
+            new_points.insert(pt, ());
+        }
+    }
+    for (to, froms) in movers.iter() {
+        if froms.len() == 1 {
+            new_points.insert(*to, ());
+            any_moved = true;
+        } else {
+            for from in froms {
+                new_points.insert(*from, ());
+            }
+        }
+    }
+    assert_eq!(points.len(), new_points.len());
+    *points = new_points;
+    any_moved
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 23;
+
+    type Input = SparseGrid<()>;
+    type PartOne = isize;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let map = Grid::parse(input, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(format!("unexpected '{}'", c).into()),
+        })?;
+        let mut result = SparseGrid::new();
+        for (pos, _) in map.iter().filter(|(_, elf)| **elf) {
+            result.insert([pos[0] as isize, pos[1] as isize], ());
+        }
+        Ok(result)
+    }
+
+    fn part_one(points: &Self::Input) -> Option<isize> {
+        let mut points = points.clone();
+        for iteration in 0..10 {
+            do_round(&mut points, iteration);
+        }
+        let rect_size = points.bounds()?.area();
+        Some((rect_size - points.len()) as isize)
+    }
+
+    fn part_two(points: &Self::Input) -> Option<i32> {
+        let mut points = points.clone();
+        let mut iteration = 0;
+        loop {
+            let moved = do_round(&mut points, iteration);
+            iteration += 1;
+            if !moved {
+                return Some(iteration)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day23::parse(&crate::read_file("examples", 2022, 23)).unwrap();
+        assert_eq!(Day23::part_one(&input), Some(110));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day23::parse(&crate::read_file("examples", 2022, 23)).unwrap();
+        assert_eq!(Day23::part_two(&input), Some(20));
+    }
+}
@@ -0,0 +1,168 @@
+use crate::error::SolveError;
+use crate::helpers::memo::Memo;
+use crate::helpers::{Grid, Point2, NEIGHBOURS4};
+use crate::{memo, Solution};
+
+/// The blizzards inside the walls, with a bit set for every direction one is heading in.
+/// Bits follow the order of `NEIGHBOURS4`: up, right, down, left.
+#[derive(Debug, Clone)]
+pub struct Valley {
+    blizzards: Grid<u8>,
+    height: isize,
+    width: isize,
+}
+
+impl Valley {
+    fn next_valley(&self) -> Self {
+        let mut next = Grid::new(self.blizzards.width(), self.blizzards.height(), 0);
+        for (pos, directions) in self.blizzards.iter() {
+            for (dir, delta) in NEIGHBOURS4.iter().enumerate() {
+                if directions & (1 << dir) != 0 {
+                    next[self.blizzards.step_wrapping(pos, *delta)] |= 1 << dir;
+                }
+            }
+        }
+        Valley {
+            blizzards: next,
+            height: self.height,
+            width: self.width,
+        }
+    }
+
+    fn next_moves(&self, pt: Point2<isize>, target: &Point2<isize>) -> Vec<Point2<isize>> {
+        let mut result = vec![];
+        for delta in [[1, 0], [0, 1], [0, -1], [0, 0], [-1, 0]] {
+            let next = pt + Point2::from(delta);
+            if next == *target {
+                return vec![*target];
+            }
+            if (next.x == 0 && next.y == -1) || (next.x == self.width - 1 && next.y == self.height)
+            {
+                result.push(next);
+                continue;
+            }
+            match self.blizzards.checked([next.x, next.y]) {
+                Some(pos) if self.blizzards[pos] == 0 => result.push(next),
+                _ => {}
+            }
+        }
+        result
+    }
+}
+
+fn shortest_path(
+    valleys: &mut Vec<Valley>,
+    time: usize,
+    position: Point2<isize>,
+    target: &Point2<isize>,
+    best: &mut usize,
+    memo: &mut Memo<(Point2<isize>, usize), usize>,
+) -> usize {
+    if time >= *best {
+        return 999;
+    }
+    let state = (position, time);
+    memo!(memo, state => {
+        let &(position, time) = state;
+        let mut result = 999;
+        let new_time = time + 1;
+        if new_time == valleys.len() {
+            let next = valleys.last().unwrap().next_valley();
+            valleys.push(next);
+        }
+        for mv in valleys[new_time].next_moves(position, target) {
+            if mv == *target {
+                *best = (*best).min(new_time);
+                return new_time;
+            }
+            let shortest = shortest_path(valleys, new_time, mv, target, best, memo);
+            result = result.min(shortest);
+        }
+        result
+    })
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 24;
+
+    type Input = Valley;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let lines: Vec<&str> = input.trim().split("\n").collect();
+        if lines.len() < 3 {
+            return Err("expected a valley surrounded by walls".into());
+        }
+        // only the inside of the walls is kept, so that blizzards can wrap around the grid.
+        let inside = lines[1..lines.len() - 1]
+            .iter()
+            .map(|line| line.get(1..line.len().saturating_sub(1)).unwrap_or_default())
+            .collect::<Vec<&str>>()
+            .join("\n");
+        let blizzards = Grid::parse(&inside, |c| match c {
+            '.' => Ok(0),
+            '^' => Ok(1 << 0),
+            '>' => Ok(1 << 1),
+            'v' => Ok(1 << 2),
+            '<' => Ok(1 << 3),
+            _ => Err(format!("unexpected '{}'", c).into()),
+        })
+        .map_err(|e| e.offset(2))?;
+        Ok(Valley {
+            height: blizzards.height() as isize,
+            width: blizzards.width() as isize,
+            blizzards,
+        })
+    }
+
+    fn part_one(valley: &Self::Input) -> Option<usize> {
+        let width = valley.width;
+        let height = valley.height;
+        let mut valleys = vec![valley.clone()];
+        let start = Point2::new(0, -1);
+        let target = Point2::new(width - 1, height);
+        let mut memo = Memo::new();
+        let result = shortest_path(&mut valleys, 0, start, &target, &mut 999, &mut memo);
+        memo.report("day 24 part one");
+        Some(result)
+    }
+
+    fn part_two(valley: &Self::Input) -> Option<usize> {
+        let width = valley.width;
+        let height = valley.height;
+        let mut valleys = vec![valley.clone()];
+        let start = Point2::new(0, -1);
+        let end = Point2::new(width - 1, height);
+        let mut memo = Memo::new();
+
+        let first = shortest_path(&mut valleys, 0, start, &end, &mut 999, &mut memo);
+        memo.clear();
+        let second = shortest_path(&mut valleys, first, end, &start, &mut 999, &mut memo);
+        memo.clear();
+        let third = shortest_path(&mut valleys, second, start, &end, &mut 999, &mut memo);
+        memo.report("day 24 part two");
+
+        Some(third)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day24::parse(&crate::read_file("examples", 2022, 24)).unwrap();
+        assert_eq!(Day24::part_one(&input), Some(18));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day24::parse(&crate::read_file("examples", 2022, 24)).unwrap();
+        assert_eq!(Day24::part_two(&input), Some(54));
+    }
+}
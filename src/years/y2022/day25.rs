@@ -0,0 +1,101 @@
+use crate::error::{parse_lines, SolveError};
+use crate::Solution;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 25;
+
+    type Input = Vec<Vec<i32>>;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input, |line| {
+            line.chars()
+                .rev()
+                .map(|d| match d {
+                    '=' => Ok(-2),
+                    '-' => Ok(-1),
+                    '0' => Ok(0),
+                    '1' => Ok(1),
+                    '2' => Ok(2),
+                    _ => Err(format!("'{}' is not a SNAFU digit", d).into()),
+                })
+                .collect()
+        })
+    }
+
+    fn part_one(amounts: &Self::Input) -> Option<String> {
+        let mut result = Vec::new();
+        for amount in amounts {
+            for (i,d) in amount.iter().enumerate() {
+                if i == result.len() {
+                    result.push(*d);
+                } else {
+                    result[i] += d;
+                }
+            }
+        }
+        loop {
+            let mut changed = false;
+            for i in 0..result.len() {
+                while result[i] > 2 {
+                    changed = true;
+                    result[i] -= 5;
+                    if i+1 == result.len() {
+                        result.push(1);
+                    } else {
+                        result[i+1] += 1;
+                    }
+                }
+                while result[i] < -2 {
+                    changed = true;
+                    result[i] += 5;
+                    if i+1 == result.len() {
+                        result.push(-1);
+                    } else {
+                        result[i+1] -= 1;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        let mut buf = String::new();
+        for d in result.iter().rev() {
+            buf.push(match d {
+                -2 => '=',
+                -1 => '-',
+                0 => '0',
+                1 => '1',
+                2 => '2',
+                _ => unreachable!()
+            });
+        }
+        Some(buf)
+    }
+
+    fn part_two(_: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day25::parse(&crate::read_file("examples", 2022, 25)).unwrap();
+        assert_eq!(Day25::part_one(&input), Some("2=-1=0".to_string()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day25::parse(&crate::read_file("examples", 2022, 25)).unwrap();
+        assert_eq!(Day25::part_two(&input), None);
+    }
+}
@@ -0,0 +1,31 @@
+/*
+ * Every solved day of 2022 is registered here so it can be run in-process by the runner.
+ * `cargo scaffold` appends new days to the list below.
+ */
+register! {
+    day01 => Day01,
+    day02 => Day02,
+    day03 => Day03,
+    day04 => Day04,
+    day05 => Day05,
+    day06 => Day06,
+    day07 => Day07,
+    day08 => Day08,
+    day09 => Day09,
+    day10 => Day10,
+    day11 => Day11,
+    day12 => Day12,
+    day13 => Day13,
+    day14 => Day14,
+    day15 => Day15,
+    day16 => Day16,
+    day17 => Day17,
+    day18 => Day18,
+    day19 => Day19,
+    day20 => Day20,
+    day21 => Day21,
+    day22 => Day22,
+    day23 => Day23,
+    day24 => Day24,
+    day25 => Day25,
+}